@@ -2,11 +2,21 @@
 pub const USER_PROFILE_SEED: &[u8] = b"user_profile";
 pub const HEALTH_RECORD_SEED: &[u8] = b"health_record";
 pub const ACCESS_GRANT_SEED: &[u8] = b"access_grant";
+pub const COLLECTION_GRANT_SEED: &[u8] = b"collection_grant";
 pub const EMERGENCY_ACCESS_SEED: &[u8] = b"emergency_access";
 pub const DAO_GOVERNANCE_SEED: &[u8] = b"dao_governance";
 pub const WEARABLE_DEVICE_SEED: &[u8] = b"wearable_device";
 pub const SYNC_STATE_SEED: &[u8] = b"sync_state";
 pub const ZK_PROOF_SEED: &[u8] = b"zk_proof";
+pub const RESEARCH_ATTESTOR_SEED: &[u8] = b"research_attestor";
+pub const RESEARCHER_CREDENTIAL_SEED: &[u8] = b"researcher_credential";
+pub const HMS_NFT_SEED: &[u8] = b"hms_nft";
+pub const NFT_AUTHORITY_SEED: &[u8] = b"nft_authority";
+pub const HMS_COLLECTION_SEED: &[u8] = b"hms_collection";
+pub const CERTIFICATE_ISSUER_SEED: &[u8] = b"certificate_issuer";
+pub const HEALTH_CERTIFICATE_SEED: &[u8] = b"health_certificate";
+pub const HMS_TOKEN_SEED: &[u8] = b"hms_token";
+pub const ACCESS_TICKET_SEED: &[u8] = b"access_ticket";
 
 // Permission Bitmasks
 pub const PERMISSION_READ: u8 = 1 << 0;   // 0001
@@ -20,7 +30,14 @@ pub const MAX_RECORD_TYPE_LENGTH: usize = 32;
 pub const MAX_ENCRYPTED_URI_LENGTH: usize = 128;
 pub const MAX_METADATA_LENGTH: usize = 256;
 pub const MAX_DEVICE_ID_LENGTH: usize = 64;
-pub const MAX_SYNC_KEY_LENGTH: usize = 32;
+pub const MAX_SYNC_DEVICES: usize = 8;
+pub const DEFAULT_SYNC_OPERATION_TIMEOUT: i64 = 60 * 60; // 1 hour
+pub const MAX_SYNC_OPERATION_TIMEOUT: i64 = SECONDS_PER_DAY * 7;
+pub const MAX_RESEARCH_RECORD_TYPES: usize = 8;
+pub const MAX_INSTITUTION_LENGTH: usize = 64;
+pub const MAX_COHORT_CRITERIA_LENGTH: usize = 128;
+pub const MAX_NFT_PURPOSE_LENGTH: usize = 32;
+pub const MAX_CERTIFICATE_TYPE_LENGTH: usize = 32;
 
 // Time Constants
 pub const SECONDS_PER_DAY: i64 = 86_400;
@@ -33,9 +50,17 @@ pub const ZK_PROOF_SIZE: usize = 256; // Size of ZK proof in bytes
 pub const ZK_PUBLIC_INPUT_SIZE: usize = 32; // Size of public inputs
 
 // DAO Governance Constants
-pub const MIN_RESEARCH_VOTES: u64 = 100;
-pub const RESEARCH_PROPOSAL_DURATION: i64 = SECONDS_PER_DAY * 14; // 2 weeks
+pub const BASIS_POINTS_DENOMINATOR: u16 = 10_000;
+pub const MIN_APPROVAL_THRESHOLD_BPS: u16 = 5_000; // simple majority
+pub const MAX_VOTING_PERIOD: i64 = SECONDS_PER_DAY * 90;
+pub const MAX_EXECUTION_DELAY: i64 = SECONDS_PER_DAY * 30;
+pub const EARLY_QUEUE_THRESHOLD_BPS: u16 = 6_667; // two-thirds of votes in favour
+pub const DEPOSIT_SLASH_THRESHOLD_BPS: u16 = 6_667; // two-thirds of votes against
+pub const COMPENSATION_CLAIM_WINDOW: i64 = SECONDS_PER_DAY * 30; // after research access ends
 
 // Emergency Access Constants
 pub const MAX_EMERGENCY_CONTACTS: usize = 5;
-pub const EMERGENCY_COOLDOWN: i64 = SECONDS_PER_DAY; // 24 hours between activations
\ No newline at end of file
+pub const EMERGENCY_COOLDOWN: i64 = SECONDS_PER_DAY; // 24 hours between activations
+
+// Contribution Reward Constants
+pub const MAX_REWARD_EPOCH_DURATION: i64 = SECONDS_PER_DAY * 30;
@@ -47,6 +47,15 @@ pub enum HealthManagerError {
     #[msg("Insufficient permissions")]
     InsufficientPermissions,
 
+    #[msg("Credential token is not held by the accessor")]
+    InvalidCredentialToken,
+
+    #[msg("Credential NFT is not a verified member of the granted collection")]
+    CredentialNotInCollection,
+
+    #[msg("Any profile holder can mint into the HMS collection, so it cannot gate access")]
+    OpenCollectionNotAllowed,
+
     // ZK Proof Errors
     #[msg("Invalid ZK proof")]
     InvalidZkProof,
@@ -83,6 +92,101 @@ pub enum HealthManagerError {
     #[msg("Already voted on this proposal")]
     AlreadyVoted,
 
+    #[msg("Research consent has been withdrawn")]
+    ResearchConsentWithdrawn,
+
+    #[msg("Research consent is already active")]
+    ResearchConsentActive,
+
+    #[msg("Record type not covered by research consent")]
+    RecordTypeNotConsented,
+
+    #[msg("Too many record types specified")]
+    TooManyRecordTypes,
+
+    #[msg("Invalid research scope")]
+    InvalidResearchScope,
+
+    #[msg("Record is outside the research proposal scope")]
+    RecordOutsideResearchScope,
+
+    #[msg("Research access period has ended")]
+    ResearchAccessExpired,
+
+    #[msg("Invalid governance parameters")]
+    InvalidGovernanceParameters,
+
+    #[msg("Voting period has not ended")]
+    VotingPeriodActive,
+
+    #[msg("Parameter proposal voting period has ended")]
+    ParameterProposalExpired,
+
+    #[msg("Proposal has already been executed")]
+    ProposalAlreadyExecuted,
+
+    #[msg("Proposal did not reach quorum or the approval threshold")]
+    ProposalNotPassed,
+
+    #[msg("Treasury withdrawal proposal voting period has ended")]
+    TreasuryProposalExpired,
+
+    #[msg("Treasury does not hold enough lamports for this withdrawal")]
+    InsufficientTreasuryFunds,
+
+    #[msg("Research proposal already finalized")]
+    ResearchProposalFinalized,
+
+    #[msg("Research proposal has not been finalized")]
+    ResearchProposalNotFinalized,
+
+    #[msg("Research proposal passed and must be executed")]
+    ResearchProposalPassed,
+
+    #[msg("Research proposal already has votes")]
+    ResearchProposalHasVotes,
+
+    #[msg("Research proposal already queued for execution")]
+    ResearchProposalAlreadyQueued,
+
+    #[msg("Research proposal has not been queued for execution")]
+    ResearchProposalNotQueued,
+
+    #[msg("Execution timelock has not elapsed")]
+    ExecutionTimelockActive,
+
+    #[msg("Proposal deposit already settled")]
+    DepositAlreadySettled,
+
+    #[msg("Proposal deposit has not been settled")]
+    DepositNotSettled,
+
+    #[msg("Vote tally would underflow")]
+    VoteTallyUnderflow,
+
+    // Research Compensation Errors
+    #[msg("Invalid compensation amount")]
+    InvalidCompensationAmount,
+
+    #[msg("Insufficient compensation funds in vault")]
+    InsufficientCompensationFunds,
+
+    #[msg("No compensation to claim")]
+    NothingToClaim,
+
+    #[msg("Compensation claim window is still open")]
+    CompensationClaimWindowOpen,
+
+    // Researcher Credential Errors
+    #[msg("Attestor is not trusted")]
+    AttestorNotTrusted,
+
+    #[msg("Researcher credential is revoked or expired")]
+    InvalidResearcherCredential,
+
+    #[msg("Institution name exceeds maximum length")]
+    InstitutionTooLong,
+
     // Wearable Integration Errors
     #[msg("Wearable device not registered")]
     WearableDeviceNotRegistered,
@@ -108,4 +212,112 @@ pub enum HealthManagerError {
 
     #[msg("Sync conflict detected")]
     SyncConflict,
-}
\ No newline at end of file
+
+    #[msg("Sync operation is still pending")]
+    SyncOperationPending,
+
+    #[msg("Invalid sync key")]
+    InvalidSyncKey,
+
+    #[msg("Owner already has a primary sync device")]
+    PrimaryDeviceExists,
+
+    #[msg("Sync device is not the primary device")]
+    NotPrimaryDevice,
+
+    #[msg("Maximum number of sync devices reached")]
+    TooManySyncDevices,
+
+    #[msg("Sync source is behind the target device")]
+    StaleSyncSource,
+
+    #[msg("Sync key must be rotated after a device revocation")]
+    SyncKeyRotationRequired,
+
+    #[msg("Sync operation was started before a device revocation")]
+    SyncOperationInvalidated,
+
+    #[msg("Invalid sync operation timeout")]
+    InvalidSyncTimeout,
+
+    #[msg("Sync operation has timed out")]
+    SyncOperationTimedOut,
+
+    #[msg("Sync operation has not timed out yet")]
+    SyncOperationNotTimedOut,
+
+    // HMS NFT Errors
+    #[msg("NFT purpose exceeds maximum length")]
+    NftPurposeTooLong,
+
+    #[msg("NFT has already been re-issued")]
+    NftAlreadyReissued,
+
+    #[msg("Only soulbound NFTs can be re-issued")]
+    NftNotSoulbound,
+
+    #[msg("Signer does not hold the NFT")]
+    NftNotHeld,
+
+    // Health Certificate Errors
+    #[msg("Certificate issuer is not registered or has been revoked")]
+    IssuerNotRegistered,
+
+    #[msg("Certificate type exceeds maximum length")]
+    CertificateTypeTooLong,
+
+    #[msg("Certificate has already been revoked")]
+    CertificateAlreadyRevoked,
+
+    // HMS Token Errors
+    #[msg("Token amount must be greater than zero")]
+    InvalidTokenAmount,
+
+    // Access Ticket Errors
+    #[msg("Sender does not hold the access ticket")]
+    NotTicketHolder,
+
+    #[msg("Caller is not the ticket holder, its approved address or an approved operator")]
+    NotApprovedForTicket,
+
+    #[msg("Access ticket was issued for a different record")]
+    TicketRecordMismatch,
+
+    // Access Pricing Errors
+    #[msg("Access period has not been paid for")]
+    AccessPaymentRequired,
+
+    #[msg("Access is already paid for up to the grant's expiry")]
+    AccessPaidToGrantExpiry,
+
+    #[msg("Access grant has no per-period price")]
+    AccessPriceNotPeriodic,
+
+    #[msg("Payment token accounts do not match the access price")]
+    InvalidPaymentAccount,
+
+    #[msg("Access price is higher than the caller's maximum")]
+    AccessPriceAboveMax,
+
+    #[msg("Access price is in a different token than the caller expected")]
+    AccessPriceMintMismatch,
+
+    #[msg("Access grant already has the current layout")]
+    AccessGrantUpToDate,
+
+    // Contribution Reward Errors
+    #[msg("Invalid reward schedule parameters")]
+    InvalidRewardSchedule,
+
+    #[msg("This kind of contribution is not currently rewarded")]
+    ContributionNotRewarded,
+
+    #[msg("Reward cap for the current epoch has been reached")]
+    RewardEpochCapReached,
+
+    #[msg("Reward schedule proposal voting period has ended")]
+    RewardProposalExpired,
+
+    #[msg("Health certificate is revoked, expired or from an unregistered issuer")]
+    InvalidCertificate,
+}
@@ -54,6 +54,24 @@ pub struct AuthorizedRecordAccess {
     pub owner: Pubkey,
     pub record_id: u64,
     pub accessor: Pubkey,
+    /// Credential collection that authorized the access, if any
+    pub via_collection: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CollectionAccessGranted {
+    pub owner: Pubkey,
+    pub collection: Pubkey,
+    pub permissions: u8,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CollectionAccessRevoked {
+    pub owner: Pubkey,
+    pub collection: Pubkey,
     pub timestamp: i64,
 }
 
@@ -105,18 +123,181 @@ pub struct ResearchProposalCreated {
     pub proposal_id: u64,
     pub researcher: Pubkey,
     pub research_topic: String,
+    pub record_types: Vec<String>,
+    pub access_expires_at: i64,
     pub expires_at: i64,
     pub timestamp: i64,
 }
 
 #[event]
 pub struct ResearchVoteCast {
+    pub proposal_id: u64,
+    pub voter: Pubkey,
+    pub cast_by: Pubkey,
+    pub vote: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ResearchVoteChanged {
+    pub proposal_id: u64,
+    pub voter: Pubkey,
+    pub cast_by: Pubkey,
+    pub vote: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoteDelegated {
+    pub delegator: Pubkey,
+    pub delegate: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoteDelegationRevoked {
+    pub delegator: Pubkey,
+    pub delegate: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ResearchProposalQueued {
+    pub proposal_id: u64,
+    pub yes_votes: u64,
+    pub no_votes: u64,
+    pub executable_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ResearchProposalExecuted {
+    pub proposal_id: u64,
+    pub researcher: Pubkey,
+    pub yes_votes: u64,
+    pub no_votes: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ResearchProposalRejected {
+    pub proposal_id: u64,
+    pub yes_votes: u64,
+    pub no_votes: u64,
+    pub finalizer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ResearchProposalCancelled {
+    pub proposal_id: u64,
+    pub researcher: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ResearchProposalClosed {
+    pub proposal_id: u64,
+    pub researcher: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ResearchVoteClosed {
+    pub proposal_id: u64,
+    pub voter: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProposalDepositRefunded {
+    pub proposal_id: u64,
+    pub researcher: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProposalDepositSlashed {
+    pub proposal_id: u64,
+    pub researcher: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ResearchConsentGranted {
+    pub proposal_id: u64,
+    pub data_owner: Pubkey,
+    pub record_types: Vec<String>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ResearchConsentWithdrawn {
+    pub proposal_id: u64,
+    pub data_owner: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GovernanceConfigUpdated {
+    pub quorum_votes: u64,
+    pub approval_threshold_bps: u16,
+    pub voting_period: i64,
+    pub execution_delay: i64,
+    pub proposal_deposit: u64,
+    pub parameter_proposal_id: Option<u64>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ParameterChangeProposed {
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub quorum_votes: u64,
+    pub approval_threshold_bps: u16,
+    pub voting_period: i64,
+    pub execution_delay: i64,
+    pub proposal_deposit: u64,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ParameterVoteCast {
+    pub proposal_id: u64,
+    pub voter: Pubkey,
+    pub vote: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TreasuryWithdrawalProposed {
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TreasuryWithdrawalVoteCast {
     pub proposal_id: u64,
     pub voter: Pubkey,
     pub vote: bool,
     pub timestamp: i64,
 }
 
+#[event]
+pub struct TreasuryWithdrawn {
+    pub proposal_id: u64,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct ResearchAccessGranted {
     pub proposal_id: u64,
@@ -125,6 +306,66 @@ pub struct ResearchAccessGranted {
     pub timestamp: i64,
 }
 
+// Research Compensation Events
+#[event]
+pub struct ResearchCompensationFunded {
+    pub proposal_id: u64,
+    pub researcher: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub payout_per_record: u64,
+    pub payout_per_participant: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ResearchCompensationClaimed {
+    pub proposal_id: u64,
+    pub data_owner: Pubkey,
+    pub records_compensated: u64,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ResearchCompensationRefunded {
+    pub proposal_id: u64,
+    pub researcher: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+// Researcher Credential Events
+#[event]
+pub struct AttestorRegistered {
+    pub attestor: Pubkey,
+    pub name: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AttestorRevoked {
+    pub attestor: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ResearcherCredentialIssued {
+    pub researcher: Pubkey,
+    pub attestor: Pubkey,
+    pub institution: String,
+    pub irb_approval_hash: [u8; 32],
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ResearcherCredentialRevoked {
+    pub researcher: Pubkey,
+    pub attestor: Pubkey,
+    pub timestamp: i64,
+}
+
 // Wearable Integration Events
 #[event]
 pub struct WearableDeviceRegistered {
@@ -152,12 +393,267 @@ pub struct SyncStateInitialized {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct SyncStateUpdated {
+    pub owner: Pubkey,
+    pub device_id: String,
+    pub sync_version: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SyncPrimaryChanged {
+    pub owner: Pubkey,
+    pub previous_primary: String,
+    pub new_primary: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SyncDeviceRevoked {
+    pub owner: Pubkey,
+    pub device_id: String,
+    pub key_epoch: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SyncKeyRotated {
+    pub owner: Pubkey,
+    pub key_epoch: u64,
+    pub sync_key_commitment: [u8; 32],
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct SyncOperationCompleted {
     pub owner: Pubkey,
+    pub operation_id: u64,
     pub source_device: String,
     pub target_device: String,
     pub sync_version: u64,
     pub records_synced: u64,
     pub timestamp: i64,
-}
\ No newline at end of file
+}
+
+#[event]
+pub struct SyncOperationFailed {
+    pub owner: Pubkey,
+    pub operation_id: u64,
+    pub source_device: String,
+    pub target_device: String,
+    pub error_message: String,
+    pub timed_out: bool,
+    pub failed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SyncOperationClosed {
+    pub owner: Pubkey,
+    pub operation_id: u64,
+    pub source_device: String,
+    pub target_device: String,
+    pub timestamp: i64,
+}
+
+// HMS NFT Events
+#[event]
+pub struct HmsCollectionInitialized {
+    pub collection_mint: Pubkey,
+    pub nft_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct HmsNftMinted {
+    pub mint: Pubkey,
+    pub profile: Pubkey,
+    pub owner: Pubkey,
+    pub collection: Pubkey,
+    pub purpose: String,
+    pub is_soulbound: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct HmsNftReissued {
+    pub previous_mint: Pubkey,
+    pub new_mint: Pubkey,
+    pub previous_profile: Pubkey,
+    pub profile: Pubkey,
+    pub previous_owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub timestamp: i64,
+}
+
+// Health Certificate Events
+#[event]
+pub struct CertificateIssuerRegistered {
+    pub issuer: Pubkey,
+    pub name: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CertificateIssuerRevoked {
+    pub issuer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct HealthCertificateIssued {
+    pub mint: Pubkey,
+    pub issuer: Pubkey,
+    pub patient: Pubkey,
+    pub record_id: u64,
+    pub data_hash: [u8; 32],
+    pub certificate_type: String,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct HealthCertificateRevoked {
+    pub mint: Pubkey,
+    pub issuer: Pubkey,
+    pub timestamp: i64,
+}
+
+// HMS Token Events
+#[event]
+pub struct HmsTokenInitialized {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub decimals: u8,
+    pub reward_per_contribution: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DataContributionRewarded {
+    pub contributor: Pubkey,
+    pub record_id: u64,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+// Access Ticket Events
+#[event]
+pub struct AccessTicketMinted {
+    pub mint: Pubkey,
+    pub record_owner: Pubkey,
+    pub record_id: u64,
+    pub permissions: u8,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccessTicketApproved {
+    pub mint: Pubkey,
+    pub holder: Pubkey,
+    pub approved: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TicketOperatorApprovalSet {
+    pub holder: Pubkey,
+    pub operator: Pubkey,
+    pub approved: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccessTicketTransferred {
+    pub mint: Pubkey,
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub caller: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccessTicketRevoked {
+    pub mint: Pubkey,
+    pub record_owner: Pubkey,
+    pub holder: Pubkey,
+    pub timestamp: i64,
+}
+
+// Access Pricing Events
+#[event]
+pub struct AccessPriceSet {
+    pub owner: Pubkey,
+    pub grantee: Pubkey,
+    pub amount: u64,
+    pub mint: Option<Pubkey>,
+    pub period: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccessPricePaid {
+    pub owner: Pubkey,
+    pub grantee: Pubkey,
+    pub amount: u64,
+    pub mint: Option<Pubkey>,
+    pub paid_until: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccessGrantMigrated {
+    pub owner: Pubkey,
+    pub grantee: Pubkey,
+    pub timestamp: i64,
+}
+
+// Contribution Reward Events
+#[event]
+pub struct RewardScheduleUpdated {
+    pub wearable_batch_reward: u64,
+    pub attested_record_reward: u64,
+    pub epoch_duration: i64,
+    pub contributor_epoch_cap: u64,
+    pub epoch_budget: u64,
+    pub reward_proposal_id: Option<u64>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RewardScheduleProposed {
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RewardScheduleVoteCast {
+    pub proposal_id: u64,
+    pub voter: Pubkey,
+    pub vote: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WearableBatchRewarded {
+    pub contributor: Pubkey,
+    pub device_id: String,
+    pub batch_id: u64,
+    pub amount: u64,
+    pub epoch: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AttestedRecordRewarded {
+    pub contributor: Pubkey,
+    pub record_id: u64,
+    pub certificate_mint: Pubkey,
+    pub amount: u64,
+    pub epoch: u64,
+    pub timestamp: i64,
+}
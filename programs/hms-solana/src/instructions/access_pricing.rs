@@ -0,0 +1,221 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount};
+use crate::{
+    constants::*,
+    errors::HealthManagerError,
+    events::{AccessGrantMigrated, AccessPriceSet, AccessPricePaid},
+    state::{AccessGrant, AccessPrice},
+};
+
+#[derive(Accounts)]
+#[instruction(grantee: Pubkey)]
+pub struct SetAccessPrice<'info> {
+    #[account(
+        mut,
+        seeds = [ACCESS_GRANT_SEED, owner.key().as_ref(), grantee.as_ref()],
+        bump = access_grant.bump,
+        constraint = access_grant.owner == owner.key() @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub access_grant: Account<'info, AccessGrant>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn set_access_price(
+    ctx: Context<SetAccessPrice>,
+    grantee: Pubkey,
+    price: Option<AccessPrice>,
+) -> Result<()> {
+    let access_grant = &mut ctx.accounts.access_grant;
+    let owner = &ctx.accounts.owner;
+
+    // Periods already paid for stay paid when the price changes
+    access_grant.set_price(price.clone())?;
+
+    // Emit event
+    emit!(AccessPriceSet {
+        owner: owner.key(),
+        grantee,
+        amount: price.as_ref().map(|p| p.amount).unwrap_or(0),
+        mint: price.as_ref().and_then(|p| p.mint),
+        period: price.as_ref().map(|p| p.period).unwrap_or(0),
+        timestamp: access_grant.last_updated,
+    });
+
+    msg!("Access price for {} set by owner: {}", grantee, owner.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct PayAccessPeriod<'info> {
+    #[account(
+        mut,
+        seeds = [ACCESS_GRANT_SEED, owner.key().as_ref(), grantee.key().as_ref()],
+        bump = access_grant.bump,
+        constraint = access_grant.is_valid() @ HealthManagerError::AccessGrantExpired
+    )]
+    pub access_grant: Account<'info, AccessGrant>,
+
+    /// CHECK: Owner of the records, validated through the access grant seeds
+    #[account(mut)]
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub grantee: Signer<'info>,
+
+    /// Required when the price is in an SPL token
+    #[account(mut)]
+    pub grantee_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Required when the price is in an SPL token
+    #[account(mut)]
+    pub owner_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn pay_access_period(
+    ctx: Context<PayAccessPeriod>,
+    max_price: u64,
+    expected_mint: Option<Pubkey>,
+) -> Result<()> {
+    let price = ctx.accounts.access_grant.price.clone()
+        .filter(|p| !p.is_per_read())
+        .ok_or(HealthManagerError::AccessPriceNotPeriodic)?;
+    price.check_limit(max_price, expected_mint)?;
+
+    // The grant's expiry caps the paid period, so the last one may cost less
+    let (amount, paid_until) = ctx.accounts.access_grant.record_period_payment(&price)?;
+
+    collect_access_payment(
+        &AccessPrice { amount, ..price },
+        ctx.accounts.grantee.to_account_info(),
+        ctx.accounts.owner.to_account_info(),
+        ctx.accounts.grantee_token_account.as_ref(),
+        ctx.accounts.owner_token_account.as_ref(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+    )?;
+
+    let access_grant = &ctx.accounts.access_grant;
+
+    // Emit event
+    emit!(AccessPricePaid {
+        owner: access_grant.owner,
+        grantee: access_grant.grantee,
+        amount,
+        mint: price.mint,
+        paid_until,
+        timestamp: access_grant.last_updated,
+    });
+
+    msg!("Access to {} paid until {} by: {}", access_grant.owner, paid_until, access_grant.grantee);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(grantee: Pubkey)]
+pub struct MigrateAccessGrant<'info> {
+    /// CHECK: Grants created before pricing are too short to deserialize as
+    /// `AccessGrant`; the discriminator is checked in the handler
+    #[account(
+        mut,
+        seeds = [ACCESS_GRANT_SEED, owner.key().as_ref(), grantee.as_ref()],
+        bump,
+        owner = crate::ID @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub access_grant: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_access_grant(
+    ctx: Context<MigrateAccessGrant>,
+    grantee: Pubkey,
+) -> Result<()> {
+    let access_grant = ctx.accounts.access_grant.to_account_info();
+    let owner = &ctx.accounts.owner;
+
+    require!(
+        access_grant.try_borrow_data()?.starts_with(AccessGrant::DISCRIMINATOR),
+        HealthManagerError::UnauthorizedAccess
+    );
+    require!(
+        access_grant.data_len() < AccessGrant::LEN,
+        HealthManagerError::AccessGrantUpToDate
+    );
+
+    // Owner covers the extra rent for the larger account
+    let required = Rent::get()?.minimum_balance(AccessGrant::LEN);
+    let shortfall = required.saturating_sub(access_grant.lamports());
+    if shortfall > 0 {
+        let cpi_accounts = anchor_lang::system_program::Transfer {
+            from: owner.to_account_info(),
+            to: access_grant.clone(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+        anchor_lang::system_program::transfer(cpi_ctx, shortfall)?;
+    }
+
+    // The new bytes are zeroed, which decodes as `price: None, paid_until: 0`
+    access_grant.resize(AccessGrant::LEN)?;
+
+    // Emit event
+    emit!(AccessGrantMigrated {
+        owner: owner.key(),
+        grantee,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Access grant for {} migrated by owner: {}", grantee, owner.key());
+    Ok(())
+}
+
+/// Move `price.amount` from the grantee to the owner, in SOL or the priced token
+pub(crate) fn collect_access_payment<'info>(
+    price: &AccessPrice,
+    grantee: AccountInfo<'info>,
+    owner: AccountInfo<'info>,
+    grantee_token_account: Option<&Account<'info, TokenAccount>>,
+    owner_token_account: Option<&Account<'info, TokenAccount>>,
+    system_program: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+) -> Result<()> {
+    match price.mint {
+        None => {
+            let cpi_accounts = anchor_lang::system_program::Transfer {
+                from: grantee,
+                to: owner,
+            };
+            let cpi_ctx = CpiContext::new(system_program, cpi_accounts);
+            anchor_lang::system_program::transfer(cpi_ctx, price.amount)?;
+        }
+        Some(mint) => {
+            let grantee_token_account = grantee_token_account
+                .ok_or(HealthManagerError::InvalidPaymentAccount)?;
+            let owner_token_account = owner_token_account
+                .ok_or(HealthManagerError::InvalidPaymentAccount)?;
+
+            require!(
+                grantee_token_account.mint == mint
+                    && owner_token_account.mint == mint
+                    && owner_token_account.owner == owner.key(),
+                HealthManagerError::InvalidPaymentAccount
+            );
+
+            let cpi_accounts = token::Transfer {
+                from: grantee_token_account.to_account_info(),
+                to: owner_token_account.to_account_info(),
+                authority: grantee,
+            };
+            let cpi_ctx = CpiContext::new(token_program, cpi_accounts);
+            token::transfer(cpi_ctx, price.amount)?;
+        }
+    }
+
+    Ok(())
+}
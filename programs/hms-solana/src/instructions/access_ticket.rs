@@ -0,0 +1,509 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, spl_token::instruction::AuthorityType, Mint, Token, TokenAccount};
+use crate::{
+    constants::*,
+    errors::HealthManagerError,
+    events::{
+        AccessTicketApproved, AccessTicketMinted, AccessTicketRevoked, AccessTicketTransferred,
+        TicketOperatorApprovalSet,
+    },
+    state::{AccessTicket, HealthRecord, TicketOperatorApproval},
+};
+
+#[derive(Accounts)]
+#[instruction(record_id: u64)]
+pub struct MintAccessTicket<'info> {
+    #[account(
+        seeds = [HEALTH_RECORD_SEED, record_owner.key().as_ref(), record_id.to_le_bytes().as_ref()],
+        bump = record.bump,
+        constraint = record.owner == record_owner.key() @ HealthManagerError::UnauthorizedAccess,
+        constraint = record.is_accessible() @ HealthManagerError::RecordSoftDeleted
+    )]
+    pub record: Account<'info, HealthRecord>,
+
+    #[account(
+        init,
+        payer = record_owner,
+        space = AccessTicket::LEN,
+        seeds = [ACCESS_TICKET_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub ticket: Account<'info, AccessTicket>,
+
+    /// CHECK: PDA signer acting as mint and freeze authority, and as the
+    /// delegate through which operators move tickets
+    #[account(
+        seeds = [NFT_AUTHORITY_SEED],
+        bump
+    )]
+    pub nft_authority: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = record_owner,
+        mint::decimals = 0,
+        mint::authority = nft_authority,
+        mint::freeze_authority = nft_authority,
+    )]
+    pub mint: Account<'info, Mint>,
+
+    /// The record owner is the first holder of the ticket
+    #[account(
+        init,
+        payer = record_owner,
+        associated_token::mint = mint,
+        associated_token::authority = record_owner,
+    )]
+    pub holder_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub record_owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn mint_access_ticket(
+    ctx: Context<MintAccessTicket>,
+    record_id: u64,
+    permissions: u8,
+    expires_at: i64,
+) -> Result<()> {
+    let ticket = &mut ctx.accounts.ticket;
+    let record_owner = &ctx.accounts.record_owner;
+
+    **ticket = AccessTicket::new(
+        ctx.accounts.mint.key(),
+        record_owner.key(),
+        record_id,
+        permissions,
+        expires_at,
+        ctx.bumps.ticket,
+    )?;
+
+    let signer_seeds: &[&[&[u8]]] = &[&[NFT_AUTHORITY_SEED, &[ctx.bumps.nft_authority]]];
+
+    let cpi_accounts = token::MintTo {
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.holder_token_account.to_account_info(),
+        authority: ctx.accounts.nft_authority.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    token::mint_to(cpi_ctx, 1)?;
+
+    // Drop the mint authority so the supply stays at one
+    let cpi_accounts = token::SetAuthority {
+        current_authority: ctx.accounts.nft_authority.to_account_info(),
+        account_or_mint: ctx.accounts.mint.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    token::set_authority(cpi_ctx, AuthorityType::MintTokens, None)?;
+
+    // Delegate the token to the program so the holder's operators can move it
+    let cpi_accounts = token::Approve {
+        to: ctx.accounts.holder_token_account.to_account_info(),
+        delegate: ctx.accounts.nft_authority.to_account_info(),
+        authority: record_owner.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::approve(cpi_ctx, 1)?;
+
+    // Emit event
+    emit!(AccessTicketMinted {
+        mint: ticket.mint,
+        record_owner: record_owner.key(),
+        record_id,
+        permissions,
+        expires_at,
+        timestamp: ticket.created_at,
+    });
+
+    msg!("Access ticket {} minted for record {}", ticket.mint, record_id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(mint: Pubkey)]
+pub struct ApproveAccessTicket<'info> {
+    #[account(
+        seeds = [ACCESS_TICKET_SEED, mint.as_ref()],
+        bump = ticket.bump
+    )]
+    pub ticket: Account<'info, AccessTicket>,
+
+    /// Token account holding the ticket; its owner is the holder
+    #[account(
+        mut,
+        token::mint = ticket.mint,
+        token::authority = holder,
+        constraint = holder_token_account.amount == 1 @ HealthManagerError::NotTicketHolder
+    )]
+    pub holder_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Address to approve; omit to clear the approval
+    pub approved: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: PDA delegate through which operators move tickets
+    #[account(
+        seeds = [NFT_AUTHORITY_SEED],
+        bump
+    )]
+    pub nft_authority: UncheckedAccount<'info>,
+
+    pub holder: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn approve_access_ticket(
+    ctx: Context<ApproveAccessTicket>,
+    mint: Pubkey,
+) -> Result<()> {
+    let holder = &ctx.accounts.holder;
+    let approved = ctx.accounts.approved.as_ref().map(|a| a.key());
+
+    // A token account has a single delegate, so approving an address takes
+    // the ticket out of the operators' reach until the approval is cleared
+    let delegate = match ctx.accounts.approved.as_ref() {
+        Some(approved) => approved.to_account_info(),
+        None => ctx.accounts.nft_authority.to_account_info(),
+    };
+
+    let cpi_accounts = token::Approve {
+        to: ctx.accounts.holder_token_account.to_account_info(),
+        delegate,
+        authority: holder.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::approve(cpi_ctx, 1)?;
+
+    // Emit event
+    emit!(AccessTicketApproved {
+        mint,
+        holder: holder.key(),
+        approved,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Access ticket {} approval set by: {}", mint, holder.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(operator: Pubkey)]
+pub struct ApproveTicketOperator<'info> {
+    #[account(
+        init,
+        payer = holder,
+        space = TicketOperatorApproval::LEN,
+        seeds = [ACCESS_TICKET_SEED, b"operator", holder.key().as_ref(), operator.as_ref()],
+        bump
+    )]
+    pub operator_approval: Account<'info, TicketOperatorApproval>,
+
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn approve_ticket_operator(
+    ctx: Context<ApproveTicketOperator>,
+    operator: Pubkey,
+) -> Result<()> {
+    let operator_approval = &mut ctx.accounts.operator_approval;
+    let holder = &ctx.accounts.holder;
+
+    require!(operator != holder.key(), HealthManagerError::CannotGrantAccessToSelf);
+
+    **operator_approval = TicketOperatorApproval::new(
+        holder.key(),
+        operator,
+        ctx.bumps.operator_approval,
+    )?;
+
+    // Emit event
+    emit!(TicketOperatorApprovalSet {
+        holder: holder.key(),
+        operator,
+        approved: true,
+        timestamp: operator_approval.approved_at,
+    });
+
+    msg!("Ticket operator {} approved by: {}", operator, holder.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(operator: Pubkey)]
+pub struct RevokeTicketOperator<'info> {
+    #[account(
+        mut,
+        seeds = [ACCESS_TICKET_SEED, b"operator", holder.key().as_ref(), operator.as_ref()],
+        bump = operator_approval.bump,
+        close = holder
+    )]
+    pub operator_approval: Account<'info, TicketOperatorApproval>,
+
+    #[account(mut)]
+    pub holder: Signer<'info>,
+}
+
+pub fn revoke_ticket_operator(
+    ctx: Context<RevokeTicketOperator>,
+    operator: Pubkey,
+) -> Result<()> {
+    let holder = &ctx.accounts.holder;
+
+    // Emit event
+    emit!(TicketOperatorApprovalSet {
+        holder: holder.key(),
+        operator,
+        approved: false,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Ticket operator {} revoked by: {}", operator, holder.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(mint: Pubkey)]
+pub struct TransferAccessTicket<'info> {
+    #[account(
+        seeds = [ACCESS_TICKET_SEED, mint.as_ref()],
+        bump = ticket.bump
+    )]
+    pub ticket: Account<'info, AccessTicket>,
+
+    #[account(address = ticket.mint)]
+    pub ticket_mint: Account<'info, Mint>,
+
+    /// Token account holding the ticket; its owner is the current holder
+    #[account(
+        mut,
+        token::mint = ticket_mint,
+        constraint = from_token_account.amount == 1 @ HealthManagerError::NotTicketHolder
+    )]
+    pub from_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Wallet receiving the ticket
+    pub recipient: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = caller,
+        associated_token::mint = ticket_mint,
+        associated_token::authority = recipient,
+    )]
+    pub to_token_account: Account<'info, TokenAccount>,
+
+    /// Present when the caller is an approved operator of the holder
+    #[account(
+        seeds = [ACCESS_TICKET_SEED, b"operator", from_token_account.owner.as_ref(), caller.key().as_ref()],
+        bump = operator_approval.bump,
+    )]
+    pub operator_approval: Option<Account<'info, TicketOperatorApproval>>,
+
+    /// CHECK: PDA delegate through which operators move tickets
+    #[account(
+        seeds = [NFT_AUTHORITY_SEED],
+        bump
+    )]
+    pub nft_authority: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn transfer_access_ticket(
+    ctx: Context<TransferAccessTicket>,
+    mint: Pubkey,
+) -> Result<()> {
+    let from_token_account = &ctx.accounts.from_token_account;
+    let caller = &ctx.accounts.caller;
+    let from = from_token_account.owner;
+    let approved: Option<Pubkey> = from_token_account.delegate.into();
+
+    require!(
+        AccessTicket::is_approved_or_owner(
+            from,
+            approved,
+            caller.key(),
+            ctx.accounts.operator_approval.is_some(),
+        ),
+        HealthManagerError::NotApprovedForTicket
+    );
+
+    let authority = if caller.key() == from || approved == Some(caller.key()) {
+        caller.to_account_info()
+    } else {
+        // Operators move the ticket through the program, which must still be
+        // its delegate
+        require!(
+            approved == Some(ctx.accounts.nft_authority.key()),
+            HealthManagerError::NotApprovedForTicket
+        );
+        ctx.accounts.nft_authority.to_account_info()
+    };
+
+    let signer_seeds: &[&[&[u8]]] = &[&[NFT_AUTHORITY_SEED, &[ctx.bumps.nft_authority]]];
+    let cpi_accounts = token::Transfer {
+        from: from_token_account.to_account_info(),
+        to: ctx.accounts.to_token_account.to_account_info(),
+        authority,
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    token::transfer(cpi_ctx, 1)?;
+
+    // Emit event
+    emit!(AccessTicketTransferred {
+        mint,
+        from,
+        to: ctx.accounts.recipient.key(),
+        caller: caller.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Access ticket {} transferred from {} to {}", mint, from, ctx.accounts.recipient.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(mint: Pubkey)]
+pub struct RevokeAccessTicket<'info> {
+    #[account(
+        mut,
+        seeds = [ACCESS_TICKET_SEED, mint.as_ref()],
+        bump = ticket.bump,
+        constraint = ticket.record_owner == record_owner.key() @ HealthManagerError::UnauthorizedAccess,
+        close = record_owner
+    )]
+    pub ticket: Account<'info, AccessTicket>,
+
+    #[account(
+        mut,
+        address = ticket.mint
+    )]
+    pub ticket_mint: Account<'info, Mint>,
+
+    /// Token account holding the ticket, whoever its holder is
+    #[account(
+        mut,
+        token::mint = ticket_mint,
+        constraint = holder_token_account.amount == 1 @ HealthManagerError::NotTicketHolder
+    )]
+    pub holder_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA signer acting as freeze authority and operator delegate
+    #[account(
+        seeds = [NFT_AUTHORITY_SEED],
+        bump
+    )]
+    pub nft_authority: UncheckedAccount<'info>,
+
+    /// The record owner can withdraw a ticket from whoever holds it
+    #[account(mut)]
+    pub record_owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn revoke_access_ticket(
+    ctx: Context<RevokeAccessTicket>,
+    mint: Pubkey,
+) -> Result<()> {
+    let record_owner = &ctx.accounts.record_owner;
+    let holder_token_account = &ctx.accounts.holder_token_account;
+    let nft_authority = &ctx.accounts.nft_authority;
+    let signer_seeds: &[&[&[u8]]] = &[&[NFT_AUTHORITY_SEED, &[ctx.bumps.nft_authority]]];
+
+    let delegate: Option<Pubkey> = holder_token_account.delegate.into();
+    if delegate == Some(nft_authority.key()) {
+        // Still delegated to the program, so the token can be burned outright
+        let cpi_accounts = token::Burn {
+            mint: ctx.accounts.ticket_mint.to_account_info(),
+            from: holder_token_account.to_account_info(),
+            authority: nft_authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        token::burn(cpi_ctx, 1)?;
+    } else {
+        // Only the holder or its approved address can burn it, so freeze it in place
+        let cpi_accounts = token::FreezeAccount {
+            account: holder_token_account.to_account_info(),
+            mint: ctx.accounts.ticket_mint.to_account_info(),
+            authority: nft_authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        token::freeze_account(cpi_ctx)?;
+    }
+
+    // Emit event
+    emit!(AccessTicketRevoked {
+        mint,
+        record_owner: record_owner.key(),
+        holder: holder_token_account.owner,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Access ticket {} revoked by: {}", mint, record_owner.key());
+
+    // Account will be closed automatically due to close constraint
+    Ok(())
+}
+
+/// Check that `holder` may use `permission` on a record through an access
+/// ticket, reading the holder from the token account that holds it
+pub(crate) fn verify_ticket_access(
+    ticket: &AccessTicket,
+    ticket_token_account: &TokenAccount,
+    holder: Pubkey,
+    record: &HealthRecord,
+    permission: u8,
+) -> Result<()> {
+    require!(
+        ticket_token_account.mint == ticket.mint &&
+        ticket_token_account.owner == holder &&
+        ticket_token_account.amount == 1,
+        HealthManagerError::NotTicketHolder
+    );
+
+    require!(
+        ticket.record_owner == record.owner && ticket.record_id == record.id,
+        HealthManagerError::TicketRecordMismatch
+    );
+
+    require!(
+        ticket.has_permission(permission),
+        HealthManagerError::InsufficientPermissions
+    );
+
+    Ok(())
+}
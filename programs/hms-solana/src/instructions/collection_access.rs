@@ -0,0 +1,171 @@
+use anchor_lang::prelude::*;
+use anchor_spl::metadata::{mpl_token_metadata::accounts::Metadata, MetadataAccount};
+use anchor_spl::token::TokenAccount;
+use crate::{
+    constants::*,
+    errors::HealthManagerError,
+    events::{CollectionAccessGranted, CollectionAccessRevoked},
+    state::{CollectionAccessGrant, UserHealthProfile},
+};
+
+#[derive(Accounts)]
+#[instruction(collection: Pubkey, expires_at: i64, permissions: u8)]
+pub struct GrantCollectionAccess<'info> {
+    #[account(
+        mut,
+        seeds = [USER_PROFILE_SEED, owner.key().as_ref()],
+        bump = profile.bump,
+        constraint = profile.owner == owner.key() @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub profile: Account<'info, UserHealthProfile>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = CollectionAccessGrant::LEN,
+        constraint = collection != hms_collection.key() @ HealthManagerError::OpenCollectionNotAllowed,
+        seeds = [COLLECTION_GRANT_SEED, owner.key().as_ref(), collection.as_ref()],
+        bump
+    )]
+    pub collection_grant: Account<'info, CollectionAccessGrant>,
+
+    /// CHECK: Mint of the HMS passport collection; only its address is used
+    #[account(
+        seeds = [HMS_COLLECTION_SEED],
+        bump
+    )]
+    pub hms_collection: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn grant_collection_access(
+    ctx: Context<GrantCollectionAccess>,
+    collection: Pubkey,
+    expires_at: i64,
+    permissions: u8,
+) -> Result<()> {
+    let collection_grant = &mut ctx.accounts.collection_grant;
+    let profile = &mut ctx.accounts.profile;
+    let owner = &ctx.accounts.owner;
+
+    **collection_grant = CollectionAccessGrant::new(
+        owner.key(),
+        collection,
+        expires_at,
+        permissions,
+        ctx.bumps.collection_grant,
+    )?;
+
+    // Update profile timestamp
+    profile.update_timestamp();
+
+    // Emit event
+    emit!(CollectionAccessGranted {
+        owner: owner.key(),
+        collection,
+        permissions,
+        expires_at,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Access granted to holders of collection {} by owner: {}", collection, owner.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(collection: Pubkey)]
+pub struct RevokeCollectionAccess<'info> {
+    #[account(
+        mut,
+        seeds = [USER_PROFILE_SEED, owner.key().as_ref()],
+        bump = profile.bump,
+        constraint = profile.owner == owner.key() @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub profile: Account<'info, UserHealthProfile>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [COLLECTION_GRANT_SEED, owner.key().as_ref(), collection.as_ref()],
+        bump = collection_grant.bump,
+        constraint = collection_grant.owner == owner.key() @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub collection_grant: Account<'info, CollectionAccessGrant>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+pub fn revoke_collection_access(
+    ctx: Context<RevokeCollectionAccess>,
+    collection: Pubkey,
+) -> Result<()> {
+    let profile = &mut ctx.accounts.profile;
+    let owner = &ctx.accounts.owner;
+
+    // Update profile timestamp
+    profile.update_timestamp();
+
+    // Emit event before closing the account
+    emit!(CollectionAccessRevoked {
+        owner: owner.key(),
+        collection,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Access revoked for holders of collection {} by owner: {}", collection, owner.key());
+
+    // Account will be closed automatically due to close constraint
+    Ok(())
+}
+
+/// Check that `holder` owns a credential NFT that is a verified member of the
+/// grant's collection, and that the grant carries `permission`. Every profile
+/// holder can mint a verified member of `hms_collection` with `create_hms_nft`,
+/// so it never identifies a credential.
+pub(crate) fn verify_collection_access(
+    collection_grant: &CollectionAccessGrant,
+    hms_collection: Pubkey,
+    holder: Pubkey,
+    credential_token: &TokenAccount,
+    credential_metadata: &Account<MetadataAccount>,
+    permission: u8,
+) -> Result<()> {
+    require!(
+        collection_grant.has_permission(permission),
+        HealthManagerError::InsufficientPermissions
+    );
+
+    // Grants made before the HMS collection was excluded must not keep
+    // letting self-minted passports through
+    require!(
+        collection_grant.collection != hms_collection,
+        HealthManagerError::OpenCollectionNotAllowed
+    );
+
+    require!(
+        credential_token.owner == holder && credential_token.amount >= 1,
+        HealthManagerError::InvalidCredentialToken
+    );
+
+    // The metadata must be the canonical account for the credential's mint
+    require!(
+        credential_metadata.key() == Metadata::find_pda(&credential_token.mint).0,
+        HealthManagerError::CredentialNotInCollection
+    );
+
+    // Only the collection authority can set `verified`, so an unverified
+    // claim of membership is not enough
+    let in_collection = credential_metadata
+        .collection
+        .as_ref()
+        .map(|c| c.verified && c.key == collection_grant.collection)
+        .unwrap_or(false);
+    require!(in_collection, HealthManagerError::CredentialNotInCollection);
+
+    Ok(())
+}
@@ -0,0 +1,519 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::{
+    constants::*,
+    errors::HealthManagerError,
+    events::{
+        AttestedRecordRewarded, RewardScheduleProposed, RewardScheduleUpdated,
+        RewardScheduleVoteCast, WearableBatchRewarded,
+    },
+    instructions::mint_hms_reward,
+    program::HmsSolana,
+    state::{
+        CertificateIssuer, ContributionReward, ContributorRewards, GovernanceConfig,
+        GovernanceVote, HealthCertificate, HealthRecord, HmsTokenConfig, ProposalCounter,
+        RewardSchedule, RewardScheduleParams, RewardScheduleProposal, UserHealthProfile,
+        WearableDataBatch,
+    },
+};
+
+#[derive(Accounts)]
+pub struct InitializeRewardSchedule<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = RewardSchedule::LEN,
+        seeds = [HMS_TOKEN_SEED, b"schedule"],
+        bump
+    )]
+    pub schedule: Account<'info, RewardSchedule>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ProposalCounter::LEN,
+        seeds = [DAO_GOVERNANCE_SEED, b"reward_proposal_counter"],
+        bump
+    )]
+    pub reward_proposal_counter: Account<'info, ProposalCounter>,
+
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key()) @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub program: Program<'info, HmsSolana>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(authority.key()) @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    /// Only the program upgrade authority can bootstrap the schedule; later
+    /// changes go through reward schedule proposals
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_reward_schedule(
+    ctx: Context<InitializeRewardSchedule>,
+    params: RewardScheduleParams,
+) -> Result<()> {
+    let schedule = &mut ctx.accounts.schedule;
+    let reward_proposal_counter = &mut ctx.accounts.reward_proposal_counter;
+
+    **schedule = RewardSchedule::new(params, ctx.bumps.schedule)?;
+    **reward_proposal_counter = ProposalCounter::new(ctx.bumps.reward_proposal_counter);
+
+    // Emit event
+    emit!(RewardScheduleUpdated {
+        wearable_batch_reward: schedule.params.wearable_batch_reward,
+        attested_record_reward: schedule.params.attested_record_reward,
+        epoch_duration: schedule.params.epoch_duration,
+        contributor_epoch_cap: schedule.params.contributor_epoch_cap,
+        epoch_budget: schedule.params.epoch_budget,
+        reward_proposal_id: None,
+        timestamp: schedule.updated_at,
+    });
+
+    msg!("Reward schedule initialized by: {}", ctx.accounts.authority.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CreateRewardScheduleProposal<'info> {
+    #[account(
+        seeds = [DAO_GOVERNANCE_SEED, b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GovernanceConfig>,
+
+    #[account(
+        mut,
+        seeds = [DAO_GOVERNANCE_SEED, b"reward_proposal_counter"],
+        bump = reward_proposal_counter.bump
+    )]
+    pub reward_proposal_counter: Account<'info, ProposalCounter>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = RewardScheduleProposal::LEN,
+        seeds = [DAO_GOVERNANCE_SEED, b"reward_proposal", reward_proposal_counter.next_proposal_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Account<'info, RewardScheduleProposal>,
+
+    #[account(
+        seeds = [USER_PROFILE_SEED, proposer.key().as_ref()],
+        bump = proposer_profile.bump,
+        constraint = proposer_profile.owner == proposer.key() @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub proposer_profile: Account<'info, UserHealthProfile>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_reward_schedule_proposal(
+    ctx: Context<CreateRewardScheduleProposal>,
+    params: RewardScheduleParams,
+) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    let proposer = &ctx.accounts.proposer;
+
+    // The proposal PDA was derived from this ID, so it is taken atomically
+    let proposal_id = ctx.accounts.reward_proposal_counter.assign_next();
+
+    **proposal = RewardScheduleProposal::new(
+        proposal_id,
+        proposer.key(),
+        params,
+        &ctx.accounts.config,
+        ctx.bumps.proposal,
+    )?;
+
+    // Emit event
+    emit!(RewardScheduleProposed {
+        proposal_id,
+        proposer: proposer.key(),
+        expires_at: proposal.tally.expires_at,
+        timestamp: proposal.tally.created_at,
+    });
+
+    msg!("Reward schedule proposal {} created by: {}", proposal_id, proposer.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64, vote: bool)]
+pub struct VoteOnRewardScheduleProposal<'info> {
+    #[account(
+        mut,
+        seeds = [DAO_GOVERNANCE_SEED, b"reward_proposal", proposal_id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, RewardScheduleProposal>,
+
+    #[account(
+        init,
+        payer = voter,
+        space = GovernanceVote::LEN,
+        seeds = [DAO_GOVERNANCE_SEED, b"reward_vote", proposal_id.to_le_bytes().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub vote_record: Account<'info, GovernanceVote>,
+
+    #[account(
+        seeds = [USER_PROFILE_SEED, voter.key().as_ref()],
+        bump = voter_profile.bump,
+        constraint = voter_profile.owner == voter.key() @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub voter_profile: Account<'info, UserHealthProfile>,
+
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn vote_on_reward_schedule_proposal(
+    ctx: Context<VoteOnRewardScheduleProposal>,
+    proposal_id: u64,
+    vote: bool,
+) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    let vote_record = &mut ctx.accounts.vote_record;
+    let voter = &ctx.accounts.voter;
+
+    proposal.cast_vote(vote)?;
+
+    **vote_record = GovernanceVote::new(
+        proposal_id,
+        voter.key(),
+        vote,
+        ctx.bumps.vote_record,
+    )?;
+
+    // Emit event
+    emit!(RewardScheduleVoteCast {
+        proposal_id,
+        voter: voter.key(),
+        vote,
+        timestamp: vote_record.voted_at,
+    });
+
+    msg!("Vote cast on reward schedule proposal {} by: {}", proposal_id, voter.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct ExecuteRewardScheduleProposal<'info> {
+    #[account(
+        mut,
+        seeds = [HMS_TOKEN_SEED, b"schedule"],
+        bump = schedule.bump
+    )]
+    pub schedule: Account<'info, RewardSchedule>,
+
+    #[account(
+        mut,
+        seeds = [DAO_GOVERNANCE_SEED, b"reward_proposal", proposal_id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, RewardScheduleProposal>,
+
+    pub executor: Signer<'info>,
+}
+
+pub fn execute_reward_schedule_proposal(
+    ctx: Context<ExecuteRewardScheduleProposal>,
+    proposal_id: u64,
+) -> Result<()> {
+    let schedule = &mut ctx.accounts.schedule;
+    let proposal = &mut ctx.accounts.proposal;
+
+    proposal.execute(schedule)?;
+
+    // Emit event
+    emit!(RewardScheduleUpdated {
+        wearable_batch_reward: schedule.params.wearable_batch_reward,
+        attested_record_reward: schedule.params.attested_record_reward,
+        epoch_duration: schedule.params.epoch_duration,
+        contributor_epoch_cap: schedule.params.contributor_epoch_cap,
+        epoch_budget: schedule.params.epoch_budget,
+        reward_proposal_id: Some(proposal_id),
+        timestamp: schedule.updated_at,
+    });
+
+    msg!("Reward schedule proposal {} executed by: {}", proposal_id, ctx.accounts.executor.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeContributorRewards<'info> {
+    /// Rewards are only tracked for profile holders
+    #[account(
+        seeds = [USER_PROFILE_SEED, contributor.key().as_ref()],
+        bump = profile.bump,
+        constraint = profile.owner == contributor.key() @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub profile: Account<'info, UserHealthProfile>,
+
+    #[account(
+        init,
+        payer = contributor,
+        space = ContributorRewards::LEN,
+        seeds = [HMS_TOKEN_SEED, b"contributor", contributor.key().as_ref()],
+        bump
+    )]
+    pub contributor_rewards: Account<'info, ContributorRewards>,
+
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_contributor_rewards(ctx: Context<InitializeContributorRewards>) -> Result<()> {
+    let contributor_rewards = &mut ctx.accounts.contributor_rewards;
+    let contributor = &ctx.accounts.contributor;
+
+    **contributor_rewards = ContributorRewards::new(
+        contributor.key(),
+        ctx.bumps.contributor_rewards,
+    )?;
+
+    msg!("Contributor rewards initialized for: {}", contributor.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(device_id: String, batch_id: u64)]
+pub struct ClaimWearableBatchReward<'info> {
+    #[account(
+        mut,
+        seeds = [HMS_TOKEN_SEED, b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, HmsTokenConfig>,
+
+    #[account(
+        mut,
+        address = config.mint
+    )]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [HMS_TOKEN_SEED, b"schedule"],
+        bump = schedule.bump
+    )]
+    pub schedule: Account<'info, RewardSchedule>,
+
+    #[account(
+        mut,
+        seeds = [HMS_TOKEN_SEED, b"contributor", contributor.key().as_ref()],
+        bump = contributor_rewards.bump
+    )]
+    pub contributor_rewards: Account<'info, ContributorRewards>,
+
+    #[account(
+        seeds = [WEARABLE_DEVICE_SEED, b"batch", contributor.key().as_ref(), device_id.as_bytes(), batch_id.to_le_bytes().as_ref()],
+        bump = data_batch.bump,
+        constraint = data_batch.owner == contributor.key() @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub data_batch: Account<'info, WearableDataBatch>,
+
+    /// Each batch can only be rewarded once
+    #[account(
+        init,
+        payer = contributor,
+        space = ContributionReward::LEN,
+        seeds = [HMS_TOKEN_SEED, b"reward", data_batch.key().as_ref()],
+        bump
+    )]
+    pub reward: Account<'info, ContributionReward>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = contributor,
+    )]
+    pub contributor_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn claim_wearable_batch_reward(
+    ctx: Context<ClaimWearableBatchReward>,
+    device_id: String,
+    batch_id: u64,
+) -> Result<()> {
+    let contributor = &ctx.accounts.contributor;
+
+    // Both per-epoch caps bound what fake batches could farm
+    let schedule = &mut ctx.accounts.schedule;
+    let offered = schedule.params.wearable_batch_reward;
+    let (amount, epoch) = schedule.allocate(&mut ctx.accounts.contributor_rewards, offered)?;
+
+    let reward = &mut ctx.accounts.reward;
+    **reward = ContributionReward::new(
+        ctx.accounts.data_batch.key(),
+        contributor.key(),
+        amount,
+        ctx.bumps.reward,
+    )?;
+
+    mint_hms_reward(
+        &mut ctx.accounts.config,
+        &ctx.accounts.mint,
+        &ctx.accounts.contributor_token_account,
+        &ctx.accounts.token_program,
+        amount,
+    )?;
+
+    // Emit event
+    emit!(WearableBatchRewarded {
+        contributor: contributor.key(),
+        device_id: device_id.clone(),
+        batch_id,
+        amount,
+        epoch,
+        timestamp: reward.rewarded_at,
+    });
+
+    msg!("Wearable batch {} of {} rewarded with {} HMS", batch_id, device_id, amount);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(certificate_mint: Pubkey, record_id: u64)]
+pub struct ClaimAttestedRecordReward<'info> {
+    #[account(
+        mut,
+        seeds = [HMS_TOKEN_SEED, b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, HmsTokenConfig>,
+
+    #[account(
+        mut,
+        address = config.mint
+    )]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [HMS_TOKEN_SEED, b"schedule"],
+        bump = schedule.bump
+    )]
+    pub schedule: Account<'info, RewardSchedule>,
+
+    #[account(
+        mut,
+        seeds = [HMS_TOKEN_SEED, b"contributor", contributor.key().as_ref()],
+        bump = contributor_rewards.bump
+    )]
+    pub contributor_rewards: Account<'info, ContributorRewards>,
+
+    #[account(
+        seeds = [HEALTH_CERTIFICATE_SEED, certificate_mint.as_ref()],
+        bump = certificate.bump,
+        constraint = certificate.patient == contributor.key() @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub certificate: Account<'info, HealthCertificate>,
+
+    #[account(
+        seeds = [CERTIFICATE_ISSUER_SEED, certificate.issuer.as_ref()],
+        bump = certificate_issuer.bump
+    )]
+    pub certificate_issuer: Account<'info, CertificateIssuer>,
+
+    /// The record must still hold the data the certificate attested to
+    #[account(
+        seeds = [HEALTH_RECORD_SEED, contributor.key().as_ref(), record_id.to_le_bytes().as_ref()],
+        bump = record.bump,
+        constraint = record.key() == certificate.record @ HealthManagerError::InvalidCertificate,
+        constraint = record.data_hash == certificate.data_hash @ HealthManagerError::InvalidCertificate,
+        constraint = record.is_accessible() @ HealthManagerError::RecordSoftDeleted
+    )]
+    pub record: Account<'info, HealthRecord>,
+
+    /// Shared with `reward_data_contribution`, so a record is rewarded at most once
+    #[account(
+        init,
+        payer = contributor,
+        space = ContributionReward::LEN,
+        seeds = [HMS_TOKEN_SEED, b"reward", record.key().as_ref()],
+        bump
+    )]
+    pub reward: Account<'info, ContributionReward>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = contributor,
+    )]
+    pub contributor_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn claim_attested_record_reward(
+    ctx: Context<ClaimAttestedRecordReward>,
+    certificate_mint: Pubkey,
+    record_id: u64,
+) -> Result<()> {
+    let contributor = &ctx.accounts.contributor;
+
+    let status = ctx.accounts.certificate.status(
+        &ctx.accounts.certificate_issuer,
+        &ctx.accounts.record,
+    )?;
+    require!(status.is_valid, HealthManagerError::InvalidCertificate);
+
+    let schedule = &mut ctx.accounts.schedule;
+    let offered = schedule.params.attested_record_reward;
+    let (amount, epoch) = schedule.allocate(&mut ctx.accounts.contributor_rewards, offered)?;
+
+    let reward = &mut ctx.accounts.reward;
+    **reward = ContributionReward::new(
+        ctx.accounts.record.key(),
+        contributor.key(),
+        amount,
+        ctx.bumps.reward,
+    )?;
+
+    mint_hms_reward(
+        &mut ctx.accounts.config,
+        &ctx.accounts.mint,
+        &ctx.accounts.contributor_token_account,
+        &ctx.accounts.token_program,
+        amount,
+    )?;
+
+    // Emit event
+    emit!(AttestedRecordRewarded {
+        contributor: contributor.key(),
+        record_id,
+        certificate_mint,
+        amount,
+        epoch,
+        timestamp: reward.rewarded_at,
+    });
+
+    msg!("Attested record {} of {} rewarded with {} HMS", record_id, contributor.key(), amount);
+    Ok(())
+}
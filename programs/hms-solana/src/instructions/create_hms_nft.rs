@@ -1,20 +1,207 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, Token, TokenAccount};
-use mpl_token_metadata::pda::{find_master_edition_account, find_metadata_account};
-use mpl_token_metadata::state::{Collection, Creator, DataV2};
 use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::metadata::{
+    self,
+    mpl_token_metadata::{
+        accounts::{MasterEdition, Metadata as MetadataPda},
+        instructions::{UnverifySizedCollectionItemCpi, UnverifySizedCollectionItemCpiAccounts},
+        types::{Collection, CollectionDetails, Creator, DataV2},
+    },
+    CreateMasterEditionV3, CreateMetadataAccountsV3, Metadata, VerifySizedCollectionItem,
+};
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+use crate::{
+    constants::*,
+    errors::HealthManagerError,
+    events::{HmsCollectionInitialized, HmsNftMinted, HmsNftReissued},
+    program::HmsSolana,
+    state::{HmsNft, UserHealthProfile},
+};
 
 #[derive(Accounts)]
+pub struct InitializeHmsCollection<'info> {
+    /// CHECK: PDA signer acting as mint, update and collection authority
+    #[account(
+        seeds = [NFT_AUTHORITY_SEED],
+        bump
+    )]
+    pub nft_authority: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        seeds = [HMS_COLLECTION_SEED],
+        bump,
+        mint::decimals = 0,
+        mint::authority = nft_authority,
+        mint::freeze_authority = nft_authority,
+    )]
+    pub collection_mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = collection_mint,
+        associated_token::authority = nft_authority,
+    )]
+    pub collection_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Created by the token metadata program; address is checked
+    #[account(
+        mut,
+        address = MetadataPda::find_pda(&collection_mint.key()).0,
+    )]
+    pub collection_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Created by the token metadata program; address is checked
+    #[account(
+        mut,
+        address = MasterEdition::find_pda(&collection_mint.key()).0,
+    )]
+    pub collection_master_edition: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key()) @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub program: Program<'info, HmsSolana>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(authority.key()) @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    /// Only the program upgrade authority can create the collection
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn initialize_hms_collection(
+    ctx: Context<InitializeHmsCollection>,
+    name: String,
+    symbol: String,
+    uri: String,
+) -> Result<()> {
+    let accounts = &ctx.accounts;
+    let signer_seeds: &[&[&[u8]]] = &[&[NFT_AUTHORITY_SEED, &[ctx.bumps.nft_authority]]];
+
+    let cpi_accounts = token::MintTo {
+        mint: accounts.collection_mint.to_account_info(),
+        to: accounts.collection_token_account.to_account_info(),
+        authority: accounts.nft_authority.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    token::mint_to(cpi_ctx, 1)?;
+
+    // A sized collection, so membership can be verified with
+    // verify_sized_collection_item
+    let cpi_accounts = CreateMetadataAccountsV3 {
+        metadata: accounts.collection_metadata.to_account_info(),
+        mint: accounts.collection_mint.to_account_info(),
+        mint_authority: accounts.nft_authority.to_account_info(),
+        payer: accounts.authority.to_account_info(),
+        update_authority: accounts.nft_authority.to_account_info(),
+        system_program: accounts.system_program.to_account_info(),
+        rent: accounts.rent.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        accounts.token_metadata_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    metadata::create_metadata_accounts_v3(
+        cpi_ctx,
+        DataV2 {
+            name,
+            symbol,
+            uri,
+            seller_fee_basis_points: 0,
+            creators: Some(vec![Creator {
+                address: accounts.nft_authority.key(),
+                verified: true,
+                share: 100,
+            }]),
+            collection: None,
+            uses: None,
+        },
+        true,
+        true,
+        Some(CollectionDetails::V1 { size: 0 }),
+    )?;
+
+    let cpi_accounts = CreateMasterEditionV3 {
+        edition: accounts.collection_master_edition.to_account_info(),
+        mint: accounts.collection_mint.to_account_info(),
+        update_authority: accounts.nft_authority.to_account_info(),
+        mint_authority: accounts.nft_authority.to_account_info(),
+        payer: accounts.authority.to_account_info(),
+        metadata: accounts.collection_metadata.to_account_info(),
+        token_program: accounts.token_program.to_account_info(),
+        system_program: accounts.system_program.to_account_info(),
+        rent: accounts.rent.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        accounts.token_metadata_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    metadata::create_master_edition_v3(cpi_ctx, Some(0))?;
+
+    // Emit event
+    emit!(HmsCollectionInitialized {
+        collection_mint: accounts.collection_mint.key(),
+        nft_authority: accounts.nft_authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("HMS collection initialized: {}", accounts.collection_mint.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(name: String, symbol: String, uri: String, purpose: String)]
 pub struct CreateHmsNft<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
 
+    #[account(
+        seeds = [USER_PROFILE_SEED, authority.key().as_ref()],
+        bump = profile.bump,
+        constraint = profile.owner == authority.key() @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub profile: Account<'info, UserHealthProfile>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = HmsNft::LEN,
+        seeds = [HMS_NFT_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub hms_nft: Account<'info, HmsNft>,
+
+    /// CHECK: PDA signer acting as mint, update and collection authority
+    #[account(
+        seeds = [NFT_AUTHORITY_SEED],
+        bump
+    )]
+    pub nft_authority: UncheckedAccount<'info>,
+
     #[account(
         init,
         payer = authority,
         mint::decimals = 0,
-        mint::authority = authority,
-        mint::freeze_authority = authority,
+        mint::authority = nft_authority,
+        mint::freeze_authority = nft_authority,
     )]
     pub mint: Account<'info, Mint>,
 
@@ -26,24 +213,42 @@ pub struct CreateHmsNft<'info> {
     )]
     pub token_account: Account<'info, TokenAccount>,
 
-    /// CHECK: This is not dangerous because we are controlling the creation of the metadata account
+    /// CHECK: Created by the token metadata program; address is checked
     #[account(
         mut,
-        address = find_metadata_account(&mint.key()).0,
+        address = MetadataPda::find_pda(&mint.key()).0,
     )]
     pub metadata_account: UncheckedAccount<'info>,
 
-    /// CHECK: This is not dangerous because we are controlling the creation of the master edition account
+    /// CHECK: Created by the token metadata program; address is checked
     #[account(
         mut,
-        address = find_master_edition_account(&mint.key()).0,
+        address = MasterEdition::find_pda(&mint.key()).0,
     )]
     pub master_edition_account: UncheckedAccount<'info>,
 
+    #[account(
+        seeds = [HMS_COLLECTION_SEED],
+        bump
+    )]
+    pub collection_mint: Account<'info, Mint>,
+
+    /// CHECK: Collection metadata owned by the token metadata program; address is checked
+    #[account(
+        mut,
+        address = MetadataPda::find_pda(&collection_mint.key()).0,
+    )]
+    pub collection_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Collection master edition owned by the token metadata program; address is checked
+    #[account(
+        address = MasterEdition::find_pda(&collection_mint.key()).0,
+    )]
+    pub collection_master_edition: UncheckedAccount<'info>,
+
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
-    /// CHECK: This is not dangerous because we are controlling the creation of the metadata account
-    pub token_metadata_program: UncheckedAccount<'info>,
+    pub token_metadata_program: Program<'info, Metadata>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
 }
@@ -53,91 +258,352 @@ pub fn create_hms_nft(
     name: String,
     symbol: String,
     uri: String,
+    purpose: String,
+    soulbound: bool,
 ) -> Result<()> {
-    // Mint the NFT
-    let cpi_accounts = token::MintTo {
+    // Link the NFT to the holder's health profile
+    let hms_nft = &mut ctx.accounts.hms_nft;
+    **hms_nft = HmsNft::new(
+        ctx.accounts.mint.key(),
+        ctx.accounts.profile.key(),
+        ctx.accounts.authority.key(),
+        purpose,
+        soulbound,
+        ctx.bumps.hms_nft,
+    )?;
+
+    HmsNftMintAccounts {
+        payer: ctx.accounts.authority.to_account_info(),
+        nft_authority: ctx.accounts.nft_authority.to_account_info(),
         mint: ctx.accounts.mint.to_account_info(),
-        to: ctx.accounts.token_account.to_account_info(),
-        authority: ctx.accounts.authority.to_account_info(),
-    };
-    let cpi_program = ctx.accounts.token_program.to_account_info();
-    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-    token::mint_to(cpi_ctx, 1)?;
+        token_account: ctx.accounts.token_account.to_account_info(),
+        metadata: ctx.accounts.metadata_account.to_account_info(),
+        master_edition: ctx.accounts.master_edition_account.to_account_info(),
+        collection_mint: ctx.accounts.collection_mint.to_account_info(),
+        collection_metadata: ctx.accounts.collection_metadata.to_account_info(),
+        collection_master_edition: ctx.accounts.collection_master_edition.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        rent: ctx.accounts.rent.to_account_info(),
+    }
+    .mint(ctx.bumps.nft_authority, name, symbol, uri, soulbound)?;
 
-    // Create the metadata account
-    let creators = vec![Creator {
-        address: ctx.accounts.authority.key(),
-        verified: true,
-        share: 100,
-    }];
-
-    let data = DataV2 {
-        name,
-        symbol,
-        uri,
-        seller_fee_basis_points: 0,
-        creators: Some(creators),
-        collection: None,
-        uses: None,
-    };
+    // Emit event
+    emit!(HmsNftMinted {
+        mint: hms_nft.mint,
+        profile: hms_nft.profile,
+        owner: hms_nft.owner,
+        collection: ctx.accounts.collection_mint.key(),
+        purpose: hms_nft.purpose.clone(),
+        is_soulbound: hms_nft.is_soulbound,
+        timestamp: hms_nft.minted_at,
+    });
 
-    let ix = mpl_token_metadata::instruction::create_metadata_accounts_v3(
-        ctx.accounts.token_metadata_program.key(),
-        ctx.accounts.metadata_account.key(),
-        ctx.accounts.mint.key(),
-        ctx.accounts.authority.key(),
-        ctx.accounts.authority.key(),
-        ctx.accounts.authority.key(),
-        data.name,
-        data.symbol,
-        data.uri,
-        data.creators,
-        data.seller_fee_basis_points,
-        true,
-        true,
-        None,
-        None,
-        None,
-    );
+    msg!("HMS NFT minted for profile: {}", hms_nft.profile);
+    Ok(())
+}
 
-    solana_program::program::invoke_signed(
-        &ix,
-        &[
-            ctx.accounts.metadata_account.to_account_info(),
-            ctx.accounts.mint.to_account_info(),
-            ctx.accounts.authority.to_account_info(),
-            ctx.accounts.authority.to_account_info(),
-            ctx.accounts.token_metadata_program.to_account_info(),
-            ctx.accounts.system_program.to_account_info(),
-            ctx.accounts.rent.to_account_info(),
-        ],
-        &[],
-    )?;
+#[derive(Accounts)]
+#[instruction(name: String, symbol: String, uri: String)]
+pub struct ReissueHmsNft<'info> {
+    /// Current holder, who must approve moving the NFT to a new wallet
+    pub authority: Signer<'info>,
 
-    // Create the master edition account
-    let ix = mpl_token_metadata::instruction::create_master_edition_v3(
-        ctx.accounts.token_metadata_program.key(),
-        ctx.accounts.master_edition_account.key(),
-        ctx.accounts.mint.key(),
-        ctx.accounts.authority.key(),
-        ctx.accounts.authority.key(),
-        ctx.accounts.metadata_account.key(),
-        ctx.accounts.authority.key(),
-        Some(0),
-    );
+    /// Wallet the owner is migrating to
+    #[account(mut)]
+    pub new_owner: Signer<'info>,
+
+    /// Profile of the new wallet, which the re-issued NFT is tied to
+    #[account(
+        seeds = [USER_PROFILE_SEED, new_owner.key().as_ref()],
+        bump = new_owner_profile.bump,
+        constraint = new_owner_profile.owner == new_owner.key() @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub new_owner_profile: Account<'info, UserHealthProfile>,
+
+    #[account(
+        mut,
+        seeds = [HMS_NFT_SEED, previous_nft.mint.as_ref()],
+        bump = previous_nft.bump,
+        constraint = previous_nft.owner == authority.key() @ HealthManagerError::UnauthorizedAccess,
+        constraint = previous_nft.reissued_to.is_none() @ HealthManagerError::NftAlreadyReissued,
+        constraint = previous_nft.is_soulbound @ HealthManagerError::NftNotSoulbound
+    )]
+    pub previous_nft: Account<'info, HmsNft>,
+
+    /// The frozen token account still holding the superseded NFT
+    #[account(
+        token::mint = previous_nft.mint,
+        token::authority = authority,
+        constraint = previous_token_account.amount == 1 @ HealthManagerError::NftNotHeld
+    )]
+    pub previous_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Metadata of the superseded NFT, owned by the token metadata program; address is checked
+    #[account(
+        mut,
+        address = MetadataPda::find_pda(&previous_nft.mint).0,
+    )]
+    pub previous_metadata: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = new_owner,
+        space = HmsNft::LEN,
+        seeds = [HMS_NFT_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub hms_nft: Account<'info, HmsNft>,
+
+    /// CHECK: PDA signer acting as mint, update and collection authority
+    #[account(
+        seeds = [NFT_AUTHORITY_SEED],
+        bump
+    )]
+    pub nft_authority: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = new_owner,
+        mint::decimals = 0,
+        mint::authority = nft_authority,
+        mint::freeze_authority = nft_authority,
+    )]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = new_owner,
+        associated_token::mint = mint,
+        associated_token::authority = new_owner,
+    )]
+    pub token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Created by the token metadata program; address is checked
+    #[account(
+        mut,
+        address = MetadataPda::find_pda(&mint.key()).0,
+    )]
+    pub metadata_account: UncheckedAccount<'info>,
+
+    /// CHECK: Created by the token metadata program; address is checked
+    #[account(
+        mut,
+        address = MasterEdition::find_pda(&mint.key()).0,
+    )]
+    pub master_edition_account: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [HMS_COLLECTION_SEED],
+        bump
+    )]
+    pub collection_mint: Account<'info, Mint>,
+
+    /// CHECK: Collection metadata owned by the token metadata program; address is checked
+    #[account(
+        mut,
+        address = MetadataPda::find_pda(&collection_mint.key()).0,
+    )]
+    pub collection_metadata: UncheckedAccount<'info>,
 
-    solana_program::program::invoke_signed(
-        &ix,
-        &[
-            ctx.accounts.master_edition_account.to_account_info(),
-            ctx.accounts.mint.to_account_info(),
-            ctx.accounts.authority.to_account_info(),
-            ctx.accounts.metadata_account.to_account_info(),
-            ctx.accounts.token_metadata_program.to_account_info(),
-            ctx.accounts.rent.to_account_info(),
-        ],
-        &[],
+    /// CHECK: Collection master edition owned by the token metadata program; address is checked
+    #[account(
+        address = MasterEdition::find_pda(&collection_mint.key()).0,
+    )]
+    pub collection_master_edition: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn reissue_hms_nft(
+    ctx: Context<ReissueHmsNft>,
+    name: String,
+    symbol: String,
+    uri: String,
+) -> Result<()> {
+    let previous_nft = &mut ctx.accounts.previous_nft;
+    let hms_nft = &mut ctx.accounts.hms_nft;
+
+    // The new NFT is tied to the new wallet's profile; the old one stays frozen
+    // in the old wallet, marked as superseded and removed from the collection
+    **hms_nft = HmsNft::new(
+        ctx.accounts.mint.key(),
+        ctx.accounts.new_owner_profile.key(),
+        ctx.accounts.new_owner.key(),
+        previous_nft.purpose.clone(),
+        previous_nft.is_soulbound,
+        ctx.bumps.hms_nft,
     )?;
+    previous_nft.reissue(hms_nft.mint);
+
+    HmsNftMintAccounts {
+        payer: ctx.accounts.new_owner.to_account_info(),
+        nft_authority: ctx.accounts.nft_authority.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        token_account: ctx.accounts.token_account.to_account_info(),
+        metadata: ctx.accounts.metadata_account.to_account_info(),
+        master_edition: ctx.accounts.master_edition_account.to_account_info(),
+        collection_mint: ctx.accounts.collection_mint.to_account_info(),
+        collection_metadata: ctx.accounts.collection_metadata.to_account_info(),
+        collection_master_edition: ctx.accounts.collection_master_edition.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        rent: ctx.accounts.rent.to_account_info(),
+    }
+    .mint(ctx.bumps.nft_authority, name, symbol, uri, hms_nft.is_soulbound)?;
+
+    // A frozen token cannot be burned, so unverify the superseded mint to stop
+    // it passing collection checks
+    let signer_seeds: &[&[&[u8]]] = &[&[NFT_AUTHORITY_SEED, &[ctx.bumps.nft_authority]]];
+    UnverifySizedCollectionItemCpi::new(
+        &ctx.accounts.token_metadata_program.to_account_info(),
+        UnverifySizedCollectionItemCpiAccounts {
+            metadata: &ctx.accounts.previous_metadata.to_account_info(),
+            collection_authority: &ctx.accounts.nft_authority.to_account_info(),
+            payer: &ctx.accounts.new_owner.to_account_info(),
+            collection_mint: &ctx.accounts.collection_mint.to_account_info(),
+            collection: &ctx.accounts.collection_metadata.to_account_info(),
+            collection_master_edition_account: &ctx.accounts.collection_master_edition.to_account_info(),
+            collection_authority_record: None,
+        },
+    )
+    .invoke_signed(signer_seeds)?;
 
+    // Emit event
+    emit!(HmsNftReissued {
+        previous_mint: previous_nft.mint,
+        new_mint: hms_nft.mint,
+        previous_profile: previous_nft.profile,
+        profile: hms_nft.profile,
+        previous_owner: previous_nft.owner,
+        new_owner: hms_nft.owner,
+        timestamp: hms_nft.minted_at,
+    });
+
+    msg!("HMS NFT re-issued to: {}", hms_nft.owner);
     Ok(())
 }
+
+/// Accounts shared by every mint into the HMS collection
+pub(crate) struct HmsNftMintAccounts<'info> {
+    pub(crate) payer: AccountInfo<'info>,
+    pub(crate) nft_authority: AccountInfo<'info>,
+    pub(crate) mint: AccountInfo<'info>,
+    pub(crate) token_account: AccountInfo<'info>,
+    pub(crate) metadata: AccountInfo<'info>,
+    pub(crate) master_edition: AccountInfo<'info>,
+    pub(crate) collection_mint: AccountInfo<'info>,
+    pub(crate) collection_metadata: AccountInfo<'info>,
+    pub(crate) collection_master_edition: AccountInfo<'info>,
+    pub(crate) token_program: AccountInfo<'info>,
+    pub(crate) token_metadata_program: AccountInfo<'info>,
+    pub(crate) system_program: AccountInfo<'info>,
+    pub(crate) rent: AccountInfo<'info>,
+}
+
+impl<'info> HmsNftMintAccounts<'info> {
+    /// Mint a single token, optionally freeze it, create its metadata and
+    /// master edition, then verify it as a member of the HMS collection.
+    /// Every authority is the program PDA, so only this program can produce
+    /// NFTs that pass collection verification.
+    pub(crate) fn mint(
+        self,
+        nft_authority_bump: u8,
+        name: String,
+        symbol: String,
+        uri: String,
+        soulbound: bool,
+    ) -> Result<()> {
+        let signer_seeds: &[&[&[u8]]] = &[&[NFT_AUTHORITY_SEED, &[nft_authority_bump]]];
+
+        // Mint the NFT
+        let cpi_accounts = token::MintTo {
+            mint: self.mint.clone(),
+            to: self.token_account.clone(),
+            authority: self.nft_authority.clone(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(self.token_program.clone(), cpi_accounts, signer_seeds);
+        token::mint_to(cpi_ctx, 1)?;
+
+        // Freeze before the master edition takes over the freeze authority, so
+        // nobody can ever thaw and transfer a soulbound token
+        if soulbound {
+            let cpi_accounts = token::FreezeAccount {
+                account: self.token_account.clone(),
+                mint: self.mint.clone(),
+                authority: self.nft_authority.clone(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(self.token_program.clone(), cpi_accounts, signer_seeds);
+            token::freeze_account(cpi_ctx)?;
+        }
+
+        // Create the metadata account
+        let cpi_accounts = CreateMetadataAccountsV3 {
+            metadata: self.metadata.clone(),
+            mint: self.mint.clone(),
+            mint_authority: self.nft_authority.clone(),
+            payer: self.payer.clone(),
+            update_authority: self.nft_authority.clone(),
+            system_program: self.system_program.clone(),
+            rent: self.rent.clone(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(self.token_metadata_program.clone(), cpi_accounts, signer_seeds);
+        metadata::create_metadata_accounts_v3(
+            cpi_ctx,
+            DataV2 {
+                name,
+                symbol,
+                uri,
+                seller_fee_basis_points: 0,
+                creators: Some(vec![Creator {
+                    address: self.nft_authority.key(),
+                    verified: true,
+                    share: 100,
+                }]),
+                collection: Some(Collection {
+                    verified: false,
+                    key: self.collection_mint.key(),
+                }),
+                uses: None,
+            },
+            true,
+            true,
+            None,
+        )?;
+
+        // Create the master edition account
+        let cpi_accounts = CreateMasterEditionV3 {
+            edition: self.master_edition.clone(),
+            mint: self.mint.clone(),
+            update_authority: self.nft_authority.clone(),
+            mint_authority: self.nft_authority.clone(),
+            payer: self.payer.clone(),
+            metadata: self.metadata.clone(),
+            token_program: self.token_program.clone(),
+            system_program: self.system_program.clone(),
+            rent: self.rent.clone(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(self.token_metadata_program.clone(), cpi_accounts, signer_seeds);
+        metadata::create_master_edition_v3(cpi_ctx, Some(0))?;
+
+        // Verify membership in the HMS collection
+        let cpi_accounts = VerifySizedCollectionItem {
+            payer: self.payer,
+            metadata: self.metadata,
+            collection_authority: self.nft_authority,
+            collection_mint: self.collection_mint,
+            collection_metadata: self.collection_metadata,
+            collection_master_edition: self.collection_master_edition,
+        };
+        let cpi_ctx = CpiContext::new_with_signer(self.token_metadata_program, cpi_accounts, signer_seeds);
+        metadata::verify_sized_collection_item(cpi_ctx, None)?;
+
+        Ok(())
+    }
+}
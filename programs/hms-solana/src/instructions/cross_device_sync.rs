@@ -2,12 +2,52 @@ use anchor_lang::prelude::*;
 use crate::{
     constants::*,
     errors::HealthManagerError,
-    events::{SyncStateInitialized, SyncOperationCompleted},
-    state::{UserHealthProfile, SyncState, SyncOperation},
+    events::{
+        SyncStateInitialized, SyncOperationCompleted, SyncOperationClosed, SyncKeyRotated,
+        SyncPrimaryChanged, SyncStateUpdated, SyncDeviceRevoked, SyncOperationFailed,
+    },
+    state::{UserHealthProfile, SyncRegistry, SyncRelation, SyncState, SyncOperation},
 };
 
 #[derive(Accounts)]
-#[instruction(device_id: String, encrypted_sync_key: [u8; 32], is_primary: bool)]
+pub struct InitializeSyncRegistry<'info> {
+    #[account(
+        seeds = [USER_PROFILE_SEED, owner.key().as_ref()],
+        bump = profile.bump,
+        constraint = profile.owner == owner.key() @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub profile: Account<'info, UserHealthProfile>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = SyncRegistry::LEN,
+        seeds = [SYNC_STATE_SEED, b"registry", owner.key().as_ref()],
+        bump
+    )]
+    pub registry: Account<'info, SyncRegistry>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_sync_registry(
+    ctx: Context<InitializeSyncRegistry>,
+    sync_key_commitment: [u8; 32],
+) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+    let owner = &ctx.accounts.owner;
+
+    **registry = SyncRegistry::new(owner.key(), sync_key_commitment, ctx.bumps.registry)?;
+
+    msg!("Sync registry initialized for: {}", owner.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(device_id: String, sync_key_proof: [u8; 32], is_primary: bool)]
 pub struct InitializeSyncState<'info> {
     #[account(
         mut,
@@ -17,6 +57,13 @@ pub struct InitializeSyncState<'info> {
     )]
     pub profile: Account<'info, UserHealthProfile>,
 
+    #[account(
+        mut,
+        seeds = [SYNC_STATE_SEED, b"registry", owner.key().as_ref()],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, SyncRegistry>,
+
     #[account(
         init,
         payer = owner,
@@ -35,18 +82,25 @@ pub struct InitializeSyncState<'info> {
 pub fn initialize_sync_state(
     ctx: Context<InitializeSyncState>,
     device_id: String,
-    encrypted_sync_key: [u8; MAX_SYNC_KEY_LENGTH],
+    sync_key_proof: [u8; 32],
     is_primary: bool,
 ) -> Result<()> {
     let sync_state = &mut ctx.accounts.sync_state;
+    let registry = &mut ctx.accounts.registry;
     let profile = &mut ctx.accounts.profile;
     let owner = &ctx.accounts.owner;
 
+    // A new device must already hold the current sync key
+    registry.verify_sync_key(&sync_key_proof)?;
+
+    // Only one device can be primary at a time
+    let device_slot = registry.register_device(&device_id, is_primary)?;
+
     // Initialize sync state
     **sync_state = SyncState::new(
         owner.key(),
         device_id.clone(),
-        encrypted_sync_key,
+        device_slot,
         is_primary,
         ctx.bumps.sync_state,
     )?;
@@ -67,7 +121,7 @@ pub fn initialize_sync_state(
 }
 
 #[derive(Accounts)]
-#[instruction(source_device: String, target_device: String, operation_type: String, sync_key: [u8; 32])]
+#[instruction(source_device: String, target_device: String, operation_type: String, sync_key_proof: [u8; 32])]
 pub struct StartSyncOperation<'info> {
     #[account(
         mut,
@@ -77,12 +131,18 @@ pub struct StartSyncOperation<'info> {
     )]
     pub profile: Account<'info, UserHealthProfile>,
 
+    #[account(
+        mut,
+        seeds = [SYNC_STATE_SEED, b"registry", owner.key().as_ref()],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, SyncRegistry>,
+
     #[account(
         mut,
         seeds = [SYNC_STATE_SEED, owner.key().as_ref(), source_device.as_bytes()],
         bump = source_sync_state.bump,
-        constraint = source_sync_state.owner == owner.key() @ HealthManagerError::UnauthorizedAccess,
-        constraint = source_sync_state.validate_sync_key(&sync_key) @ HealthManagerError::SyncKeyMismatch
+        constraint = source_sync_state.owner == owner.key() @ HealthManagerError::UnauthorizedAccess
     )]
     pub source_sync_state: Account<'info, SyncState>,
 
@@ -90,8 +150,7 @@ pub struct StartSyncOperation<'info> {
         mut,
         seeds = [SYNC_STATE_SEED, owner.key().as_ref(), target_device.as_bytes()],
         bump = target_sync_state.bump,
-        constraint = target_sync_state.owner == owner.key() @ HealthManagerError::UnauthorizedAccess,
-        constraint = target_sync_state.validate_sync_key(&sync_key) @ HealthManagerError::SyncKeyMismatch
+        constraint = target_sync_state.owner == owner.key() @ HealthManagerError::UnauthorizedAccess
     )]
     pub target_sync_state: Account<'info, SyncState>,
 
@@ -99,7 +158,14 @@ pub struct StartSyncOperation<'info> {
         init,
         payer = owner,
         space = SyncOperation::LEN,
-        seeds = [SYNC_STATE_SEED, b"operation", owner.key().as_ref(), source_device.as_bytes(), target_device.as_bytes()],
+        seeds = [
+            SYNC_STATE_SEED,
+            b"operation",
+            owner.key().as_ref(),
+            source_device.as_bytes(),
+            target_device.as_bytes(),
+            source_sync_state.next_operation_id.to_le_bytes().as_ref()
+        ],
         bump
     )]
     pub sync_operation: Account<'info, SyncOperation>,
@@ -115,49 +181,77 @@ pub fn start_sync_operation(
     source_device: String,
     target_device: String,
     operation_type: String,
-    _sync_key: [u8; MAX_SYNC_KEY_LENGTH],
+    sync_key_proof: [u8; 32],
 ) -> Result<()> {
     let sync_operation = &mut ctx.accounts.sync_operation;
-    let source_sync_state = &ctx.accounts.source_sync_state;
+    let registry = &mut ctx.accounts.registry;
+    let source_sync_state = &mut ctx.accounts.source_sync_state;
     let target_sync_state = &ctx.accounts.target_sync_state;
     let profile = &mut ctx.accounts.profile;
     let owner = &ctx.accounts.owner;
 
-    // Check for sync conflicts
-    let has_conflict = source_sync_state.detect_conflict(target_sync_state.state_hash);
-    if has_conflict && operation_type != "conflict_resolution" {
-        return Err(HealthManagerError::SyncConflict.into());
+    // Each operation consumes one proof of the current sync key; keys from
+    // before a device revocation are refused until the key is rotated
+    registry.verify_sync_key(&sync_key_proof)?;
+
+    // Only concurrent updates conflict; resolutions always flow from the primary device
+    let relation = registry.compare(source_sync_state.device_slot, target_sync_state.device_slot);
+    if operation_type == "conflict_resolution" {
+        require!(source_sync_state.is_primary, HealthManagerError::NotPrimaryDevice);
+    } else {
+        require!(relation != SyncRelation::Stale, HealthManagerError::StaleSyncSource);
+        require!(relation != SyncRelation::Concurrent, HealthManagerError::SyncConflict);
     }
 
+    // Each operation from the source device gets its own sequence number
+    let operation_id = source_sync_state.assign_next_operation();
+
     // Initialize sync operation
     **sync_operation = SyncOperation::new(
+        operation_id,
         owner.key(),
         source_device,
         target_device,
         operation_type,
         source_sync_state.sync_version,
         target_sync_state.sync_version,
+        registry.key_epoch,
+        registry.operation_timeout,
         ctx.bumps.sync_operation,
     )?;
 
     // Update profile timestamp
     profile.update_timestamp();
 
-    msg!("Sync operation started between devices");
+    msg!("Sync operation {} started between devices", operation_id);
     Ok(())
 }
 
 #[derive(Accounts)]
-#[instruction(source_device: String, target_device: String, records_synced: u64, new_state_hash: [u8; 32])]
+#[instruction(source_device: String, target_device: String, operation_id: u64, records_synced: u64, new_state_hash: [u8; 32])]
 pub struct CompleteSyncOperation<'info> {
     #[account(
         mut,
-        seeds = [SYNC_STATE_SEED, b"operation", owner.key().as_ref(), source_device.as_bytes(), target_device.as_bytes()],
+        seeds = [
+            SYNC_STATE_SEED,
+            b"operation",
+            owner.key().as_ref(),
+            source_device.as_bytes(),
+            target_device.as_bytes(),
+            operation_id.to_le_bytes().as_ref()
+        ],
         bump = sync_operation.bump,
         constraint = sync_operation.owner == owner.key() @ HealthManagerError::UnauthorizedAccess
     )]
     pub sync_operation: Account<'info, SyncOperation>,
 
+    #[account(
+        mut,
+        seeds = [SYNC_STATE_SEED, b"registry", owner.key().as_ref()],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, SyncRegistry>,
+
     #[account(
         mut,
         seeds = [SYNC_STATE_SEED, owner.key().as_ref(), sync_operation.source_device.as_bytes()],
@@ -182,17 +276,36 @@ pub fn complete_sync_operation(
     ctx: Context<CompleteSyncOperation>,
     _source_device: String,
     _target_device: String,
+    operation_id: u64,
     records_synced: u64,
     new_state_hash: [u8; 32],
 ) -> Result<()> {
     let sync_operation = &mut ctx.accounts.sync_operation;
+    let registry = &mut ctx.accounts.registry;
     let source_sync_state = &mut ctx.accounts.source_sync_state;
     let target_sync_state = &mut ctx.accounts.target_sync_state;
     let owner = &ctx.accounts.owner;
 
+    // A revocation or key rotation voids operations started under the old sync key
+    require!(
+        registry.is_current_key(sync_operation.key_epoch),
+        HealthManagerError::SyncOperationInvalidated
+    );
+
+    // The target may have diverged while the operation was in flight
+    let resolves_conflict = sync_operation.operation_type == "conflict_resolution";
+    let relation = registry.compare(source_sync_state.device_slot, target_sync_state.device_slot);
+    require!(
+        resolves_conflict || relation != SyncRelation::Concurrent,
+        HealthManagerError::SyncConflict
+    );
+
     // Complete the sync operation
     sync_operation.complete_successfully(records_synced)?;
 
+    // Both devices have now seen each other's updates
+    registry.merge(source_sync_state.device_slot, target_sync_state.device_slot, resolves_conflict);
+
     // Update sync states
     source_sync_state.update_sync_state(new_state_hash)?;
     target_sync_state.update_sync_state(new_state_hash)?;
@@ -200,6 +313,7 @@ pub fn complete_sync_operation(
     // Emit event
     emit!(SyncOperationCompleted {
         owner: owner.key(),
+        operation_id,
         source_device: sync_operation.source_device.clone(),
         target_device: sync_operation.target_device.clone(),
         sync_version: source_sync_state.sync_version,
@@ -212,11 +326,64 @@ pub fn complete_sync_operation(
 }
 
 #[derive(Accounts)]
-#[instruction(source_device: String, target_device: String, error_message: String)]
+#[instruction(device_id: String, new_state_hash: [u8; 32])]
+pub struct RecordSyncUpdate<'info> {
+    #[account(
+        mut,
+        seeds = [SYNC_STATE_SEED, b"registry", owner.key().as_ref()],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, SyncRegistry>,
+
+    #[account(
+        mut,
+        seeds = [SYNC_STATE_SEED, owner.key().as_ref(), device_id.as_bytes()],
+        bump = sync_state.bump,
+        constraint = sync_state.owner == owner.key() @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub sync_state: Account<'info, SyncState>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn record_sync_update(
+    ctx: Context<RecordSyncUpdate>,
+    device_id: String,
+    new_state_hash: [u8; 32],
+) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+    let sync_state = &mut ctx.accounts.sync_state;
+    let owner = &ctx.accounts.owner;
+
+    // Advance this device's own entry in its version vector
+    registry.record_local_update(sync_state.device_slot);
+    sync_state.record_local_update(new_state_hash);
+
+    // Emit event
+    emit!(SyncStateUpdated {
+        owner: owner.key(),
+        device_id,
+        sync_version: sync_state.sync_version,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Local update recorded for device: {}", sync_state.device_id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(source_device: String, target_device: String, operation_id: u64, error_message: String)]
 pub struct FailSyncOperation<'info> {
     #[account(
         mut,
-        seeds = [SYNC_STATE_SEED, b"operation", owner.key().as_ref(), source_device.as_bytes(), target_device.as_bytes()],
+        seeds = [
+            SYNC_STATE_SEED,
+            b"operation",
+            owner.key().as_ref(),
+            source_device.as_bytes(),
+            target_device.as_bytes(),
+            operation_id.to_le_bytes().as_ref()
+        ],
         bump = sync_operation.bump,
         constraint = sync_operation.owner == owner.key() @ HealthManagerError::UnauthorizedAccess
     )]
@@ -230,20 +397,165 @@ pub fn fail_sync_operation(
     ctx: Context<FailSyncOperation>,
     _source_device: String,
     _target_device: String,
+    operation_id: u64,
     error_message: String,
 ) -> Result<()> {
     let sync_operation = &mut ctx.accounts.sync_operation;
+    let owner = &ctx.accounts.owner;
 
     // Mark operation as failed
     sync_operation.complete_with_error(error_message)?;
 
+    // Emit event
+    emit!(SyncOperationFailed {
+        owner: owner.key(),
+        operation_id,
+        source_device: sync_operation.source_device.clone(),
+        target_device: sync_operation.target_device.clone(),
+        error_message: sync_operation.error_message.clone(),
+        timed_out: false,
+        failed_by: owner.key(),
+        timestamp: sync_operation.completed_at,
+    });
+
     msg!("Sync operation failed");
     Ok(())
 }
 
+#[derive(Accounts)]
+#[instruction(owner: Pubkey, source_device: String, target_device: String, operation_id: u64)]
+pub struct TimeoutSyncOperation<'info> {
+    #[account(
+        mut,
+        seeds = [
+            SYNC_STATE_SEED,
+            b"operation",
+            owner.as_ref(),
+            source_device.as_bytes(),
+            target_device.as_bytes(),
+            operation_id.to_le_bytes().as_ref()
+        ],
+        bump = sync_operation.bump,
+        constraint = sync_operation.is_timed_out() @ HealthManagerError::SyncOperationNotTimedOut
+    )]
+    pub sync_operation: Account<'info, SyncOperation>,
+
+    /// Anyone can fail an operation that has outlived its timeout
+    pub caller: Signer<'info>,
+}
+
+pub fn timeout_sync_operation(
+    ctx: Context<TimeoutSyncOperation>,
+    owner: Pubkey,
+    _source_device: String,
+    _target_device: String,
+    operation_id: u64,
+) -> Result<()> {
+    let sync_operation = &mut ctx.accounts.sync_operation;
+    let caller = &ctx.accounts.caller;
+
+    // Mark operation as failed
+    sync_operation.complete_with_error(String::from("Sync operation timed out"))?;
+
+    // Emit event
+    emit!(SyncOperationFailed {
+        owner,
+        operation_id,
+        source_device: sync_operation.source_device.clone(),
+        target_device: sync_operation.target_device.clone(),
+        error_message: sync_operation.error_message.clone(),
+        timed_out: true,
+        failed_by: caller.key(),
+        timestamp: sync_operation.completed_at,
+    });
+
+    msg!("Sync operation {} timed out", operation_id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetSyncOperationTimeout<'info> {
+    #[account(
+        mut,
+        seeds = [SYNC_STATE_SEED, b"registry", owner.key().as_ref()],
+        bump = registry.bump,
+        constraint = registry.owner == owner.key() @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub registry: Account<'info, SyncRegistry>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn set_sync_operation_timeout(
+    ctx: Context<SetSyncOperationTimeout>,
+    operation_timeout: i64,
+) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+
+    // Applies to operations started from now on
+    registry.set_operation_timeout(operation_timeout)?;
+
+    msg!("Sync operation timeout set to {} seconds", operation_timeout);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(source_device: String, target_device: String, operation_id: u64)]
+pub struct CloseSyncOperation<'info> {
+    #[account(
+        mut,
+        close = owner,
+        seeds = [
+            SYNC_STATE_SEED,
+            b"operation",
+            owner.key().as_ref(),
+            source_device.as_bytes(),
+            target_device.as_bytes(),
+            operation_id.to_le_bytes().as_ref()
+        ],
+        bump = sync_operation.bump,
+        constraint = sync_operation.owner == owner.key() @ HealthManagerError::UnauthorizedAccess,
+        constraint = !sync_operation.is_pending() @ HealthManagerError::SyncOperationPending
+    )]
+    pub sync_operation: Account<'info, SyncOperation>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+pub fn close_sync_operation(
+    ctx: Context<CloseSyncOperation>,
+    source_device: String,
+    target_device: String,
+    operation_id: u64,
+) -> Result<()> {
+    let owner = &ctx.accounts.owner;
+
+    // Emit event
+    emit!(SyncOperationClosed {
+        owner: owner.key(),
+        operation_id,
+        source_device,
+        target_device,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Sync operation {} closed", operation_id);
+
+    // Account will be closed automatically due to close constraint
+    Ok(())
+}
+
 #[derive(Accounts)]
 #[instruction(device_id: String, is_primary: bool)]
 pub struct UpdateSyncPrimary<'info> {
+    #[account(
+        mut,
+        seeds = [SYNC_STATE_SEED, b"registry", owner.key().as_ref()],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, SyncRegistry>,
+
     #[account(
         mut,
         seeds = [SYNC_STATE_SEED, owner.key().as_ref(), device_id.as_bytes()],
@@ -252,20 +564,140 @@ pub struct UpdateSyncPrimary<'info> {
     )]
     pub sync_state: Account<'info, SyncState>,
 
+    /// Required when promoting a device while another one is primary
+    #[account(
+        mut,
+        seeds = [SYNC_STATE_SEED, owner.key().as_ref(), registry.primary_device.as_bytes()],
+        bump = previous_primary.bump,
+    )]
+    pub previous_primary: Option<Account<'info, SyncState>>,
+
     #[account(mut)]
     pub owner: Signer<'info>,
 }
 
 pub fn update_sync_primary(
     ctx: Context<UpdateSyncPrimary>,
-    _device_id: String,
+    device_id: String,
     is_primary: bool,
 ) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
     let sync_state = &mut ctx.accounts.sync_state;
+    let owner = &ctx.accounts.owner;
+    let previous = registry.primary_device.clone();
+
+    if is_primary {
+        // Demote the current primary in the same instruction
+        if registry.has_primary() && !registry.is_primary(&device_id) {
+            let previous_primary = ctx
+                .accounts
+                .previous_primary
+                .as_mut()
+                .ok_or(HealthManagerError::PrimaryDeviceExists)?;
+            previous_primary.set_primary(false);
+        }
+        registry.set_primary(&device_id);
+    } else if registry.is_primary(&device_id) {
+        registry.clear_primary();
+    }
 
     // Update primary status
     sync_state.set_primary(is_primary);
 
+    if previous != registry.primary_device {
+        // Emit event
+        emit!(SyncPrimaryChanged {
+            owner: owner.key(),
+            previous_primary: previous,
+            new_primary: registry.primary_device.clone(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+    }
+
     msg!("Sync primary status updated");
     Ok(())
-}
\ No newline at end of file
+}
+
+#[derive(Accounts)]
+pub struct RotateSyncKey<'info> {
+    #[account(
+        mut,
+        seeds = [SYNC_STATE_SEED, b"registry", owner.key().as_ref()],
+        bump = registry.bump,
+        constraint = registry.owner == owner.key() @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub registry: Account<'info, SyncRegistry>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn rotate_sync_key(
+    ctx: Context<RotateSyncKey>,
+    new_sync_key_commitment: [u8; 32],
+) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+    let owner = &ctx.accounts.owner;
+
+    // The key lives on the registry, so every device moves to the new epoch at once
+    registry.rotate_sync_key(new_sync_key_commitment)?;
+
+    // Emit event
+    emit!(SyncKeyRotated {
+        owner: owner.key(),
+        key_epoch: registry.key_epoch,
+        sync_key_commitment: new_sync_key_commitment,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Sync key rotated to epoch {} by: {}", registry.key_epoch, owner.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(device_id: String)]
+pub struct RevokeSyncDevice<'info> {
+    #[account(
+        mut,
+        seeds = [SYNC_STATE_SEED, b"registry", owner.key().as_ref()],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, SyncRegistry>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [SYNC_STATE_SEED, owner.key().as_ref(), device_id.as_bytes()],
+        bump = sync_state.bump,
+        constraint = sync_state.owner == owner.key() @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub sync_state: Account<'info, SyncState>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+pub fn revoke_sync_device(
+    ctx: Context<RevokeSyncDevice>,
+    device_id: String,
+) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+    let sync_state = &ctx.accounts.sync_state;
+    let owner = &ctx.accounts.owner;
+
+    // Free the device's slot and require a new sync key; pending operations
+    // started under the old key can no longer complete
+    registry.revoke_device(&device_id, sync_state.device_slot);
+
+    // Emit event
+    emit!(SyncDeviceRevoked {
+        owner: owner.key(),
+        device_id,
+        key_epoch: registry.key_epoch,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Sync device revoked: {}", sync_state.device_id);
+
+    // Account will be closed automatically due to close constraint
+    Ok(())
+}
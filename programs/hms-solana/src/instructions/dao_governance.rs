@@ -2,22 +2,94 @@ use anchor_lang::prelude::*;
 use crate::{
     constants::*,
     errors::HealthManagerError,
-    events::{ResearchProposalCreated, ResearchVoteCast, ResearchAccessGranted},
-    state::{ResearchProposal, ResearchVote, UserHealthProfile},
+    events::{
+        ResearchProposalCreated, ResearchVoteCast, ResearchAccessGranted,
+        ResearchConsentGranted, ResearchConsentWithdrawn, ResearchProposalRejected,
+        ResearchProposalCancelled, ResearchProposalClosed, ResearchVoteClosed,
+        ResearchVoteChanged, VoteDelegated, VoteDelegationRevoked, ProposalDepositRefunded,
+        ProposalDepositSlashed, ResearchProposalQueued, ResearchProposalExecuted,
+    },
+    state::{
+        GovernanceConfig, ResearchProposal, ProposalCounter, ResearchScope, ResearchVote,
+        ResearchConsent, UserHealthProfile, VoteDelegation, ResearchAttestor,
+        ResearcherCredential, ProposalDeposit, GovernanceTreasury, ResearchRecordAccess,
+    },
 };
 
 #[derive(Accounts)]
-#[instruction(proposal_id: u64, research_topic: String)]
+pub struct InitializeProposalCounter<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = ProposalCounter::LEN,
+        seeds = [DAO_GOVERNANCE_SEED, b"proposal_counter"],
+        bump
+    )]
+    pub proposal_counter: Account<'info, ProposalCounter>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_proposal_counter(ctx: Context<InitializeProposalCounter>) -> Result<()> {
+    let proposal_counter = &mut ctx.accounts.proposal_counter;
+
+    **proposal_counter = ProposalCounter::new(ctx.bumps.proposal_counter);
+
+    msg!("Research proposal counter initialized");
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(research_topic: String, scope: ResearchScope)]
 pub struct CreateResearchProposal<'info> {
+    #[account(
+        seeds = [DAO_GOVERNANCE_SEED, b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GovernanceConfig>,
+
+    #[account(
+        mut,
+        seeds = [DAO_GOVERNANCE_SEED, b"proposal_counter"],
+        bump = proposal_counter.bump
+    )]
+    pub proposal_counter: Account<'info, ProposalCounter>,
+
     #[account(
         init,
         payer = researcher,
         space = ResearchProposal::LEN,
-        seeds = [DAO_GOVERNANCE_SEED, b"proposal", proposal_id.to_le_bytes().as_ref()],
+        seeds = [DAO_GOVERNANCE_SEED, b"proposal", proposal_counter.next_proposal_id.to_le_bytes().as_ref()],
         bump
     )]
     pub proposal: Account<'info, ResearchProposal>,
 
+    #[account(
+        init,
+        payer = researcher,
+        space = ProposalDeposit::LEN,
+        seeds = [DAO_GOVERNANCE_SEED, b"deposit", proposal_counter.next_proposal_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub deposit: Account<'info, ProposalDeposit>,
+
+    #[account(
+        seeds = [RESEARCHER_CREDENTIAL_SEED, researcher.key().as_ref()],
+        bump = credential.bump,
+        constraint = credential.is_valid() @ HealthManagerError::InvalidResearcherCredential
+    )]
+    pub credential: Account<'info, ResearcherCredential>,
+
+    #[account(
+        seeds = [RESEARCH_ATTESTOR_SEED, credential.attestor.as_ref()],
+        bump = research_attestor.bump,
+        constraint = research_attestor.is_active @ HealthManagerError::AttestorNotTrusted
+    )]
+    pub research_attestor: Account<'info, ResearchAttestor>,
+
     #[account(mut)]
     pub researcher: Signer<'info>,
 
@@ -26,17 +98,40 @@ pub struct CreateResearchProposal<'info> {
 
 pub fn create_research_proposal(
     ctx: Context<CreateResearchProposal>,
-    proposal_id: u64,
     research_topic: String,
+    scope: ResearchScope,
 ) -> Result<()> {
     let proposal = &mut ctx.accounts.proposal;
     let researcher = &ctx.accounts.researcher;
 
+    // The proposal PDA was derived from this ID, so it is taken atomically
+    let proposal_id = ctx.accounts.proposal_counter.assign_next();
+
+    // Hold the anti-spam deposit until the proposal is settled
+    let deposit = &mut ctx.accounts.deposit;
+    let deposit_amount = ctx.accounts.config.proposal_deposit;
+    if deposit_amount > 0 {
+        let cpi_accounts = anchor_lang::system_program::Transfer {
+            from: researcher.to_account_info(),
+            to: deposit.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+        anchor_lang::system_program::transfer(cpi_ctx, deposit_amount)?;
+    }
+    **deposit = ProposalDeposit::new(
+        proposal_id,
+        researcher.key(),
+        deposit_amount,
+        ctx.bumps.deposit,
+    );
+
     // Initialize the research proposal
     **proposal = ResearchProposal::new(
         proposal_id,
         researcher.key(),
         research_topic.clone(),
+        scope,
+        &ctx.accounts.config,
         ctx.bumps.proposal,
     )?;
 
@@ -45,6 +140,8 @@ pub fn create_research_proposal(
         proposal_id,
         researcher: researcher.key(),
         research_topic,
+        record_types: proposal.scope.record_types.clone(),
+        access_expires_at: proposal.scope.access_expires_at,
         expires_at: proposal.expires_at,
         timestamp: Clock::get()?.unix_timestamp,
     });
@@ -101,6 +198,7 @@ pub fn vote_on_research_proposal(
     **vote_record = ResearchVote::new(
         proposal_id,
         voter.key(),
+        voter.key(),
         vote,
         ctx.bumps.vote_record,
     )?;
@@ -109,6 +207,7 @@ pub fn vote_on_research_proposal(
     emit!(ResearchVoteCast {
         proposal_id,
         voter: voter.key(),
+        cast_by: voter.key(),
         vote,
         timestamp: Clock::get()?.unix_timestamp,
     });
@@ -117,9 +216,232 @@ pub fn vote_on_research_proposal(
     Ok(())
 }
 
+#[derive(Accounts)]
+#[instruction(proposal_id: u64, delegator: Pubkey, vote: bool)]
+pub struct CastDelegatedResearchVote<'info> {
+    #[account(
+        mut,
+        seeds = [DAO_GOVERNANCE_SEED, b"proposal", proposal_id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, ResearchProposal>,
+
+    #[account(
+        seeds = [DAO_GOVERNANCE_SEED, b"delegation", delegator.as_ref()],
+        bump = delegation.bump,
+        constraint = delegation.delegate == delegate.key() @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub delegation: Account<'info, VoteDelegation>,
+
+    /// Fails if the delegator has already voted directly
+    #[account(
+        init,
+        payer = delegate,
+        space = ResearchVote::LEN,
+        seeds = [DAO_GOVERNANCE_SEED, b"vote", proposal_id.to_le_bytes().as_ref(), delegator.as_ref()],
+        bump
+    )]
+    pub vote_record: Account<'info, ResearchVote>,
+
+    #[account(
+        seeds = [USER_PROFILE_SEED, delegator.as_ref()],
+        bump = delegator_profile.bump,
+        constraint = delegator_profile.owner == delegator @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub delegator_profile: Account<'info, UserHealthProfile>,
+
+    #[account(mut)]
+    pub delegate: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn cast_delegated_research_vote(
+    ctx: Context<CastDelegatedResearchVote>,
+    proposal_id: u64,
+    delegator: Pubkey,
+    vote: bool,
+) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    let vote_record = &mut ctx.accounts.vote_record;
+    let delegate = &ctx.accounts.delegate;
+
+    // The vote carries the delegator's weight
+    proposal.cast_vote(vote)?;
+
+    **vote_record = ResearchVote::new(
+        proposal_id,
+        delegator,
+        delegate.key(),
+        vote,
+        ctx.bumps.vote_record,
+    )?;
+
+    // Emit event
+    emit!(ResearchVoteCast {
+        proposal_id,
+        voter: delegator,
+        cast_by: delegate.key(),
+        vote,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Delegated vote cast on proposal {} for {} by: {}", proposal_id, delegator, delegate.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64, voter: Pubkey, vote: bool)]
+pub struct ChangeResearchVote<'info> {
+    #[account(
+        mut,
+        seeds = [DAO_GOVERNANCE_SEED, b"proposal", proposal_id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, ResearchProposal>,
+
+    #[account(
+        mut,
+        seeds = [DAO_GOVERNANCE_SEED, b"vote", proposal_id.to_le_bytes().as_ref(), voter.as_ref()],
+        bump = vote_record.bump,
+        constraint = vote_record.voter == voter @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub vote_record: Account<'info, ResearchVote>,
+
+    /// Required when a delegate changes a vote it cast for the voter
+    #[account(
+        seeds = [DAO_GOVERNANCE_SEED, b"delegation", voter.as_ref()],
+        bump = delegation.bump,
+    )]
+    pub delegation: Option<Account<'info, VoteDelegation>>,
+
+    pub caster: Signer<'info>,
+}
+
+pub fn change_research_vote(
+    ctx: Context<ChangeResearchVote>,
+    proposal_id: u64,
+    voter: Pubkey,
+    vote: bool,
+) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    let vote_record = &mut ctx.accounts.vote_record;
+    let caster = &ctx.accounts.caster;
+
+    // The voter can always take over their vote; a delegate can only
+    // change a vote it cast while the voter has not voted directly
+    if caster.key() != voter {
+        let delegation = ctx.accounts.delegation.as_ref()
+            .ok_or(HealthManagerError::UnauthorizedAccess)?;
+
+        require!(
+            delegation.delegate == caster.key() &&
+            !vote_record.is_direct() &&
+            vote_record.cast_by == caster.key(),
+            HealthManagerError::UnauthorizedAccess
+        );
+    }
+
+    proposal.change_vote(vote_record.vote, vote)?;
+    vote_record.change(vote, caster.key())?;
+
+    // Emit event
+    emit!(ResearchVoteChanged {
+        proposal_id,
+        voter,
+        cast_by: caster.key(),
+        vote,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Vote on proposal {} changed by: {}", proposal_id, caster.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(delegate: Pubkey)]
+pub struct DelegateResearchVotes<'info> {
+    #[account(
+        seeds = [USER_PROFILE_SEED, delegator.key().as_ref()],
+        bump = delegator_profile.bump,
+        constraint = delegator_profile.owner == delegator.key() @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub delegator_profile: Account<'info, UserHealthProfile>,
+
+    #[account(
+        init,
+        payer = delegator,
+        space = VoteDelegation::LEN,
+        seeds = [DAO_GOVERNANCE_SEED, b"delegation", delegator.key().as_ref()],
+        bump
+    )]
+    pub delegation: Account<'info, VoteDelegation>,
+
+    #[account(mut)]
+    pub delegator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn delegate_research_votes(
+    ctx: Context<DelegateResearchVotes>,
+    delegate: Pubkey,
+) -> Result<()> {
+    let delegation = &mut ctx.accounts.delegation;
+    let delegator = &ctx.accounts.delegator;
+
+    **delegation = VoteDelegation::new(
+        delegator.key(),
+        delegate,
+        ctx.bumps.delegation,
+    )?;
+
+    // Emit event
+    emit!(VoteDelegated {
+        delegator: delegator.key(),
+        delegate,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Research votes delegated to {} by: {}", delegate, delegator.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RevokeResearchVoteDelegation<'info> {
+    #[account(
+        mut,
+        close = delegator,
+        seeds = [DAO_GOVERNANCE_SEED, b"delegation", delegator.key().as_ref()],
+        bump = delegation.bump,
+        constraint = delegation.delegator == delegator.key() @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub delegation: Account<'info, VoteDelegation>,
+
+    #[account(mut)]
+    pub delegator: Signer<'info>,
+}
+
+pub fn revoke_research_vote_delegation(ctx: Context<RevokeResearchVoteDelegation>) -> Result<()> {
+    let delegation = &ctx.accounts.delegation;
+    let delegator = &ctx.accounts.delegator;
+
+    // Emit event before closing the account
+    emit!(VoteDelegationRevoked {
+        delegator: delegator.key(),
+        delegate: delegation.delegate,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Research vote delegation revoked by: {}", delegator.key());
+
+    // Account will be closed automatically due to close constraint
+    Ok(())
+}
+
 #[derive(Accounts)]
 #[instruction(proposal_id: u64)]
-pub struct ExecuteResearchProposal<'info> {
+pub struct QueueResearchProposal<'info> {
     #[account(
         mut,
         seeds = [DAO_GOVERNANCE_SEED, b"proposal", proposal_id.to_le_bytes().as_ref()],
@@ -127,7 +449,44 @@ pub struct ExecuteResearchProposal<'info> {
     )]
     pub proposal: Account<'info, ResearchProposal>,
 
-    pub executor: Signer<'info>,
+    pub queuer: Signer<'info>,
+}
+
+pub fn queue_research_proposal(
+    ctx: Context<QueueResearchProposal>,
+    proposal_id: u64,
+) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    let queuer = &ctx.accounts.queuer;
+
+    // Anyone can start the timelock once the proposal has passed
+    proposal.queue()?;
+
+    // Emit event
+    emit!(ResearchProposalQueued {
+        proposal_id,
+        yes_votes: proposal.yes_votes,
+        no_votes: proposal.no_votes,
+        executable_at: proposal.executable_at(),
+        timestamp: proposal.queued_at,
+    });
+
+    msg!("Research proposal {} queued by: {}", proposal_id, queuer.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct ExecuteResearchProposal<'info> {
+    #[account(
+        mut,
+        seeds = [DAO_GOVERNANCE_SEED, b"proposal", proposal_id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        constraint = proposal.researcher == researcher.key() @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub proposal: Account<'info, ResearchProposal>,
+
+    pub researcher: Signer<'info>,
 }
 
 pub fn execute_research_proposal(
@@ -135,12 +494,376 @@ pub fn execute_research_proposal(
     proposal_id: u64,
 ) -> Result<()> {
     let proposal = &mut ctx.accounts.proposal;
-    let executor = &ctx.accounts.executor;
+    let researcher = &ctx.accounts.researcher;
 
     // Execute the proposal (this marks it as approved)
     proposal.execute()?;
 
-    msg!("Research proposal {} executed by: {}", proposal_id, executor.key());
+    // Emit event
+    emit!(ResearchProposalExecuted {
+        proposal_id,
+        researcher: researcher.key(),
+        yes_votes: proposal.yes_votes,
+        no_votes: proposal.no_votes,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Research proposal {} executed by: {}", proposal_id, researcher.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct FinalizeResearchProposal<'info> {
+    #[account(
+        mut,
+        seeds = [DAO_GOVERNANCE_SEED, b"proposal", proposal_id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, ResearchProposal>,
+
+    pub finalizer: Signer<'info>,
+}
+
+pub fn finalize_research_proposal(
+    ctx: Context<FinalizeResearchProposal>,
+    proposal_id: u64,
+) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    let finalizer = &ctx.accounts.finalizer;
+
+    // Anyone can record the rejection of an expired, failed proposal
+    proposal.finalize()?;
+
+    // Emit event
+    emit!(ResearchProposalRejected {
+        proposal_id,
+        yes_votes: proposal.yes_votes,
+        no_votes: proposal.no_votes,
+        finalizer: finalizer.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Research proposal {} rejected", proposal_id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct CancelResearchProposal<'info> {
+    #[account(
+        mut,
+        seeds = [DAO_GOVERNANCE_SEED, b"proposal", proposal_id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        constraint = proposal.researcher == researcher.key() @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub proposal: Account<'info, ResearchProposal>,
+
+    pub researcher: Signer<'info>,
+}
+
+pub fn cancel_research_proposal(
+    ctx: Context<CancelResearchProposal>,
+    proposal_id: u64,
+) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    let researcher = &ctx.accounts.researcher;
+
+    // Only possible before the first vote is cast
+    proposal.cancel()?;
+
+    // Emit event
+    emit!(ResearchProposalCancelled {
+        proposal_id,
+        researcher: researcher.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Research proposal {} cancelled by: {}", proposal_id, researcher.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct SettleProposalDeposit<'info> {
+    #[account(
+        mut,
+        seeds = [DAO_GOVERNANCE_SEED, b"proposal", proposal_id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, ResearchProposal>,
+
+    #[account(
+        mut,
+        close = researcher,
+        seeds = [DAO_GOVERNANCE_SEED, b"deposit", proposal_id.to_le_bytes().as_ref()],
+        bump = deposit.bump,
+        constraint = deposit.depositor == researcher.key() @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub deposit: Account<'info, ProposalDeposit>,
+
+    #[account(
+        mut,
+        seeds = [DAO_GOVERNANCE_SEED, b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, GovernanceTreasury>,
+
+    /// CHECK: Receives the refund and deposit rent, validated against the deposit
+    #[account(mut)]
+    pub researcher: UncheckedAccount<'info>,
+}
+
+pub fn settle_proposal_deposit(
+    ctx: Context<SettleProposalDeposit>,
+    proposal_id: u64,
+) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    let deposit = &ctx.accounts.deposit;
+    let treasury = &mut ctx.accounts.treasury;
+    let researcher = &ctx.accounts.researcher;
+
+    proposal.settle_deposit()?;
+
+    if proposal.is_slashable() {
+        // Move the deposit to the treasury; the account rent still goes back to the researcher
+        let deposit_info = deposit.to_account_info();
+        let treasury_info = treasury.to_account_info();
+        **deposit_info.try_borrow_mut_lamports()? -= deposit.amount;
+        **treasury_info.try_borrow_mut_lamports()? += deposit.amount;
+        treasury.total_slashed += deposit.amount;
+
+        emit!(ProposalDepositSlashed {
+            proposal_id,
+            researcher: researcher.key(),
+            amount: deposit.amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Deposit for research proposal {} slashed", proposal_id);
+    } else {
+        emit!(ProposalDepositRefunded {
+            proposal_id,
+            researcher: researcher.key(),
+            amount: deposit.amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Deposit for research proposal {} refunded to: {}", proposal_id, researcher.key());
+    }
+
+    // Deposit account will be closed automatically due to close constraint
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct CloseResearchProposal<'info> {
+    #[account(
+        mut,
+        close = researcher,
+        seeds = [DAO_GOVERNANCE_SEED, b"proposal", proposal_id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        constraint = proposal.researcher == researcher.key() @ HealthManagerError::UnauthorizedAccess,
+        constraint = proposal.can_close() @ HealthManagerError::ResearchProposalNotFinalized,
+        constraint = proposal.deposit_settled @ HealthManagerError::DepositNotSettled
+    )]
+    pub proposal: Account<'info, ResearchProposal>,
+
+    #[account(mut)]
+    pub researcher: Signer<'info>,
+}
+
+pub fn close_research_proposal(
+    ctx: Context<CloseResearchProposal>,
+    proposal_id: u64,
+) -> Result<()> {
+    let researcher = &ctx.accounts.researcher;
+
+    // Emit event before closing the account
+    emit!(ResearchProposalClosed {
+        proposal_id,
+        researcher: researcher.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Research proposal {} closed by: {}", proposal_id, researcher.key());
+
+    // Account will be closed automatically due to close constraint
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct CloseResearchVote<'info> {
+    /// CHECK: May already be closed; deserialized in the handler when it still exists
+    #[account(
+        seeds = [DAO_GOVERNANCE_SEED, b"proposal", proposal_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [DAO_GOVERNANCE_SEED, b"vote", proposal_id.to_le_bytes().as_ref(), voter.key().as_ref()],
+        bump = vote_record.bump,
+        has_one = rent_payer @ HealthManagerError::UnauthorizedAccess,
+        constraint = vote_record.voter == voter.key() @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub vote_record: Account<'info, ResearchVote>,
+
+    pub voter: Signer<'info>,
+
+    /// CHECK: Receives the rent back; the voter or the delegate that cast the vote
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+}
+
+pub fn close_research_vote(
+    ctx: Context<CloseResearchVote>,
+    proposal_id: u64,
+) -> Result<()> {
+    let proposal_info = &ctx.accounts.proposal;
+    let voter = &ctx.accounts.voter;
+
+    // Votes can be reclaimed once the proposal is finalized or already closed
+    if !proposal_info.data_is_empty() {
+        let data = proposal_info.try_borrow_data()?;
+        let proposal = ResearchProposal::try_deserialize(&mut &data[..])?;
+        require!(
+            proposal.is_finalized(),
+            HealthManagerError::ResearchProposalNotFinalized
+        );
+    }
+
+    // Emit event before closing the account
+    emit!(ResearchVoteClosed {
+        proposal_id,
+        voter: voter.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Vote on proposal {} closed by: {}", proposal_id, voter.key());
+
+    // Account will be closed automatically due to close constraint
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64, record_types: Vec<String>)]
+pub struct GrantResearchConsent<'info> {
+    #[account(
+        seeds = [DAO_GOVERNANCE_SEED, b"proposal", proposal_id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, ResearchProposal>,
+
+    #[account(
+        mut,
+        seeds = [USER_PROFILE_SEED, owner.key().as_ref()],
+        bump = profile.bump,
+        constraint = profile.owner == owner.key() @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub profile: Account<'info, UserHealthProfile>,
+
+    /// Kept after withdrawal, so consent can be granted again
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = ResearchConsent::LEN,
+        seeds = [DAO_GOVERNANCE_SEED, b"consent", proposal_id.to_le_bytes().as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    pub consent: Account<'info, ResearchConsent>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn grant_research_consent(
+    ctx: Context<GrantResearchConsent>,
+    proposal_id: u64,
+    record_types: Vec<String>,
+) -> Result<()> {
+    let consent = &mut ctx.accounts.consent;
+    let profile = &mut ctx.accounts.profile;
+    let owner = &ctx.accounts.owner;
+
+    // Record the data owner's opt-in for this proposal
+    if consent.is_new() {
+        **consent = ResearchConsent::new(
+            proposal_id,
+            owner.key(),
+            record_types.clone(),
+            ctx.bumps.consent,
+        )?;
+    } else {
+        consent.regrant(record_types.clone())?;
+    }
+
+    // Update profile timestamp
+    profile.update_timestamp();
+
+    // Emit event
+    emit!(ResearchConsentGranted {
+        proposal_id,
+        data_owner: owner.key(),
+        record_types,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Research consent for proposal {} granted by: {}", proposal_id, owner.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct WithdrawResearchConsent<'info> {
+    #[account(
+        mut,
+        seeds = [USER_PROFILE_SEED, owner.key().as_ref()],
+        bump = profile.bump,
+        constraint = profile.owner == owner.key() @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub profile: Account<'info, UserHealthProfile>,
+
+    #[account(
+        mut,
+        seeds = [DAO_GOVERNANCE_SEED, b"consent", proposal_id.to_le_bytes().as_ref(), owner.key().as_ref()],
+        bump = consent.bump,
+        constraint = consent.owner == owner.key() @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub consent: Account<'info, ResearchConsent>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+pub fn withdraw_research_consent(
+    ctx: Context<WithdrawResearchConsent>,
+    proposal_id: u64,
+) -> Result<()> {
+    let consent = &mut ctx.accounts.consent;
+    let profile = &mut ctx.accounts.profile;
+    let owner = &ctx.accounts.owner;
+
+    // Withdrawn consent is kept on-chain for the audit trail
+    consent.withdraw()?;
+
+    // Update profile timestamp
+    profile.update_timestamp();
+
+    // Emit event
+    emit!(ResearchConsentWithdrawn {
+        proposal_id,
+        data_owner: owner.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Research consent for proposal {} withdrawn by: {}", proposal_id, owner.key());
     Ok(())
 }
 
@@ -155,6 +878,20 @@ pub struct AccessWithResearchGrant<'info> {
     )]
     pub proposal: Account<'info, ResearchProposal>,
 
+    #[account(
+        seeds = [RESEARCHER_CREDENTIAL_SEED, researcher.key().as_ref()],
+        bump = credential.bump,
+        constraint = credential.is_valid() @ HealthManagerError::InvalidResearcherCredential
+    )]
+    pub credential: Account<'info, ResearcherCredential>,
+
+    #[account(
+        seeds = [RESEARCH_ATTESTOR_SEED, credential.attestor.as_ref()],
+        bump = research_attestor.bump,
+        constraint = research_attestor.is_active @ HealthManagerError::AttestorNotTrusted
+    )]
+    pub research_attestor: Account<'info, ResearchAttestor>,
+
     #[account(
         seeds = [USER_PROFILE_SEED, record_owner.key().as_ref()],
         bump = profile.bump
@@ -169,10 +906,38 @@ pub struct AccessWithResearchGrant<'info> {
     )]
     pub record: Account<'info, crate::state::HealthRecord>,
 
+    #[account(
+        mut,
+        seeds = [DAO_GOVERNANCE_SEED, b"consent", proposal_id.to_le_bytes().as_ref(), record_owner.key().as_ref()],
+        bump = consent.bump,
+        constraint = consent.owner == record_owner.key() @ HealthManagerError::UnauthorizedAccess,
+        constraint = !consent.is_withdrawn @ HealthManagerError::ResearchConsentWithdrawn
+    )]
+    pub consent: Account<'info, ResearchConsent>,
+
+    /// Created on the first access, so each record is only counted once
+    #[account(
+        init_if_needed,
+        payer = researcher,
+        space = ResearchRecordAccess::LEN,
+        seeds = [
+            DAO_GOVERNANCE_SEED,
+            b"record_access",
+            proposal_id.to_le_bytes().as_ref(),
+            record_owner.key().as_ref(),
+            record_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub record_access: Account<'info, ResearchRecordAccess>,
+
     /// CHECK: This is the owner of the record, validated in constraints
     pub record_owner: UncheckedAccount<'info>,
 
+    #[account(mut)]
     pub researcher: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
 pub fn access_with_research_grant(
@@ -180,13 +945,39 @@ pub fn access_with_research_grant(
     proposal_id: u64,
     record_id: u64,
 ) -> Result<()> {
-    let _proposal = &ctx.accounts.proposal;
-    let _record = &ctx.accounts.record;
+    let proposal = &ctx.accounts.proposal;
+    let record = &ctx.accounts.record;
+    let consent = &mut ctx.accounts.consent;
     let researcher = &ctx.accounts.researcher;
     let record_owner = &ctx.accounts.record_owner;
 
-    // Additional validation can be added here
-    // For example, checking if the record type matches the research topic
+    // The approved scope bounds what the researcher can read, and for how long
+    require!(
+        proposal.scope.is_access_active(),
+        HealthManagerError::ResearchAccessExpired
+    );
+    require!(
+        proposal.scope.covers_record(&record.record_type, record.timestamp),
+        HealthManagerError::RecordOutsideResearchScope
+    );
+
+    // The data owner must have opted in for this record type
+    require!(
+        consent.covers_record_type(&record.record_type),
+        HealthManagerError::RecordTypeNotConsented
+    );
+
+    // Distinct records count towards the data owner's compensation claim
+    let record_access = &mut ctx.accounts.record_access;
+    if record_access.is_new() {
+        **record_access = ResearchRecordAccess::new(
+            proposal_id,
+            record_owner.key(),
+            record_id,
+            ctx.bumps.record_access,
+        )?;
+        consent.record_access();
+    }
 
     // Emit event for audit trail
     emit!(ResearchAccessGranted {
@@ -200,6 +991,7 @@ pub fn access_with_research_grant(
         owner: record_owner.key(),
         record_id,
         accessor: researcher.key(),
+        via_collection: None,
         timestamp: Clock::get()?.unix_timestamp,
     });
 
@@ -237,6 +237,7 @@ pub fn access_with_emergency(
         owner: record_owner.key(),
         record_id,
         accessor: accessor.key(),
+        via_collection: None,
         timestamp: Clock::get()?.unix_timestamp,
     });
 
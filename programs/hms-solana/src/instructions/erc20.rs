@@ -1,79 +0,0 @@
-#![cfg_attr(not(feature = "std"), no_std)]
-
-#[ink::contract]
-mod erc20 {
-    use ink::storage::Mapping;
-
-    #[ink(storage)]
-    pub struct Erc20 {
-        total_supply: Balance,
-        balances: Mapping<AccountId, Balance>,
-        allowances: Mapping<(AccountId, AccountId), Balance>,
-    }
-
-    impl Erc20 {
-        #[ink(constructor)]
-        pub fn new(initial_supply: Balance) -> Self {
-            let caller = Self::env().caller();
-            let mut balances = Mapping::new();
-            balances.insert(caller, &initial_supply);
-            Self {
-                total_supply: initial_supply,
-                balances,
-                allowances: Mapping::new(),
-            }
-        }
-
-        #[ink(message)]
-        pub fn total_supply(&self) -> Balance {
-            self.total_supply
-        }
-
-        #[ink(message)]
-        pub fn balance_of(&self, owner: AccountId) -> Balance {
-            self.balances.get(owner).unwrap_or(0)
-        }
-
-        #[ink(message)]
-        pub fn transfer(&mut self, to: AccountId, value: Balance) -> bool {
-            let from = self.env().caller();
-            let from_balance = self.balance_of(from);
-            if from_balance < value {
-                return false;
-            }
-            self.balances.insert(from, &(from_balance - value));
-            let to_balance = self.balance_of(to);
-            self.balances.insert(to, &(to_balance + value));
-            true
-        }
-
-        #[ink(message)]
-        pub fn approve(&mut self, spender: AccountId, value: Balance) -> bool {
-            let owner = self.env().caller();
-            self.allowances.insert((owner, spender), &value);
-            true
-        }
-
-        #[ink(message)]
-        pub fn allowance(&self, owner: AccountId, spender: AccountId) -> Balance {
-            self.allowances.get((owner, spender)).unwrap_or(0)
-        }
-
-        #[ink(message)]
-        pub fn transfer_from(&mut self, from: AccountId, to: AccountId, value: Balance) -> bool {
-            let spender = self.env().caller();
-            let allowance = self.allowance(from, spender);
-            if allowance < value {
-                return false;
-            }
-            let from_balance = self.balance_of(from);
-            if from_balance < value {
-                return false;
-            }
-            self.balances.insert(from, &(from_balance - value));
-            let to_balance = self.balance_of(to);
-            self.balances.insert(to, &(to_balance + value));
-            true
-        }
-    }
-}
\ No newline at end of file
@@ -0,0 +1,495 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    errors::HealthManagerError,
+    events::{
+        GovernanceConfigUpdated, ParameterChangeProposed, ParameterVoteCast,
+        TreasuryWithdrawalProposed, TreasuryWithdrawalVoteCast, TreasuryWithdrawn,
+    },
+    program::HmsSolana,
+    state::{
+        GovernanceConfig, GovernanceTreasury, GovernanceVote, ParameterChangeProposal,
+        ProposalCounter, TreasuryWithdrawalProposal, UserHealthProfile,
+    },
+};
+
+#[derive(Accounts)]
+pub struct InitializeGovernanceConfig<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = GovernanceConfig::LEN,
+        seeds = [DAO_GOVERNANCE_SEED, b"config"],
+        bump
+    )]
+    pub config: Account<'info, GovernanceConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = GovernanceTreasury::LEN,
+        seeds = [DAO_GOVERNANCE_SEED, b"treasury"],
+        bump
+    )]
+    pub treasury: Account<'info, GovernanceTreasury>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ProposalCounter::LEN,
+        seeds = [DAO_GOVERNANCE_SEED, b"param_proposal_counter"],
+        bump
+    )]
+    pub parameter_proposal_counter: Account<'info, ProposalCounter>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ProposalCounter::LEN,
+        seeds = [DAO_GOVERNANCE_SEED, b"treasury_proposal_counter"],
+        bump
+    )]
+    pub treasury_proposal_counter: Account<'info, ProposalCounter>,
+
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key()) @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub program: Program<'info, HmsSolana>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(authority.key()) @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    /// Only the program upgrade authority can bootstrap the config
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_governance_config(
+    ctx: Context<InitializeGovernanceConfig>,
+    quorum_votes: u64,
+    approval_threshold_bps: u16,
+    voting_period: i64,
+    execution_delay: i64,
+    proposal_deposit: u64,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let treasury = &mut ctx.accounts.treasury;
+    let parameter_proposal_counter = &mut ctx.accounts.parameter_proposal_counter;
+    let treasury_proposal_counter = &mut ctx.accounts.treasury_proposal_counter;
+
+    **config = GovernanceConfig::new(
+        quorum_votes,
+        approval_threshold_bps,
+        voting_period,
+        execution_delay,
+        proposal_deposit,
+        ctx.bumps.config,
+    )?;
+    **treasury = GovernanceTreasury::new(ctx.bumps.treasury);
+    **parameter_proposal_counter = ProposalCounter::new(ctx.bumps.parameter_proposal_counter);
+    **treasury_proposal_counter = ProposalCounter::new(ctx.bumps.treasury_proposal_counter);
+
+    // Emit event
+    emit!(GovernanceConfigUpdated {
+        quorum_votes,
+        approval_threshold_bps,
+        voting_period,
+        execution_delay,
+        proposal_deposit,
+        parameter_proposal_id: None,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Governance config initialized by: {}", ctx.accounts.authority.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CreateParameterProposal<'info> {
+    #[account(
+        seeds = [DAO_GOVERNANCE_SEED, b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GovernanceConfig>,
+
+    #[account(
+        mut,
+        seeds = [DAO_GOVERNANCE_SEED, b"param_proposal_counter"],
+        bump = parameter_proposal_counter.bump
+    )]
+    pub parameter_proposal_counter: Account<'info, ProposalCounter>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = ParameterChangeProposal::LEN,
+        seeds = [DAO_GOVERNANCE_SEED, b"param_proposal", parameter_proposal_counter.next_proposal_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Account<'info, ParameterChangeProposal>,
+
+    #[account(
+        seeds = [USER_PROFILE_SEED, proposer.key().as_ref()],
+        bump = proposer_profile.bump,
+        constraint = proposer_profile.owner == proposer.key() @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub proposer_profile: Account<'info, UserHealthProfile>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_parameter_proposal(
+    ctx: Context<CreateParameterProposal>,
+    quorum_votes: u64,
+    approval_threshold_bps: u16,
+    voting_period: i64,
+    execution_delay: i64,
+    proposal_deposit: u64,
+) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    let proposer = &ctx.accounts.proposer;
+
+    // The proposal PDA was derived from this ID, so it is taken atomically
+    let proposal_id = ctx.accounts.parameter_proposal_counter.assign_next();
+
+    **proposal = ParameterChangeProposal::new(
+        proposal_id,
+        proposer.key(),
+        quorum_votes,
+        approval_threshold_bps,
+        voting_period,
+        execution_delay,
+        proposal_deposit,
+        &ctx.accounts.config,
+        ctx.bumps.proposal,
+    )?;
+
+    // Emit event
+    emit!(ParameterChangeProposed {
+        proposal_id,
+        proposer: proposer.key(),
+        quorum_votes,
+        approval_threshold_bps,
+        voting_period,
+        execution_delay,
+        proposal_deposit,
+        expires_at: proposal.tally.expires_at,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Parameter proposal {} created by: {}", proposal_id, proposer.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64, vote: bool)]
+pub struct VoteOnParameterProposal<'info> {
+    #[account(
+        mut,
+        seeds = [DAO_GOVERNANCE_SEED, b"param_proposal", proposal_id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, ParameterChangeProposal>,
+
+    #[account(
+        init,
+        payer = voter,
+        space = GovernanceVote::LEN,
+        seeds = [DAO_GOVERNANCE_SEED, b"param_vote", proposal_id.to_le_bytes().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub vote_record: Account<'info, GovernanceVote>,
+
+    #[account(
+        seeds = [USER_PROFILE_SEED, voter.key().as_ref()],
+        bump = voter_profile.bump,
+        constraint = voter_profile.owner == voter.key() @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub voter_profile: Account<'info, UserHealthProfile>,
+
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn vote_on_parameter_proposal(
+    ctx: Context<VoteOnParameterProposal>,
+    proposal_id: u64,
+    vote: bool,
+) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    let vote_record = &mut ctx.accounts.vote_record;
+    let voter = &ctx.accounts.voter;
+
+    // Cast the vote on the proposal
+    proposal.cast_vote(vote)?;
+
+    // Record the individual vote
+    **vote_record = GovernanceVote::new(
+        proposal_id,
+        voter.key(),
+        vote,
+        ctx.bumps.vote_record,
+    )?;
+
+    // Emit event
+    emit!(ParameterVoteCast {
+        proposal_id,
+        voter: voter.key(),
+        vote,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Vote cast on parameter proposal {} by: {}", proposal_id, voter.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct ExecuteParameterProposal<'info> {
+    #[account(
+        mut,
+        seeds = [DAO_GOVERNANCE_SEED, b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GovernanceConfig>,
+
+    #[account(
+        mut,
+        seeds = [DAO_GOVERNANCE_SEED, b"param_proposal", proposal_id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, ParameterChangeProposal>,
+
+    pub executor: Signer<'info>,
+}
+
+pub fn execute_parameter_proposal(
+    ctx: Context<ExecuteParameterProposal>,
+    proposal_id: u64,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let proposal = &mut ctx.accounts.proposal;
+
+    // Apply the new parameters (only once voting has closed and the vote passed)
+    proposal.execute(config)?;
+
+    // Emit event
+    emit!(GovernanceConfigUpdated {
+        quorum_votes: config.quorum_votes,
+        approval_threshold_bps: config.approval_threshold_bps,
+        voting_period: config.voting_period,
+        execution_delay: config.execution_delay,
+        proposal_deposit: config.proposal_deposit,
+        parameter_proposal_id: Some(proposal_id),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Parameter proposal {} executed by: {}", proposal_id, ctx.accounts.executor.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CreateTreasuryWithdrawalProposal<'info> {
+    #[account(
+        seeds = [DAO_GOVERNANCE_SEED, b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GovernanceConfig>,
+
+    #[account(
+        mut,
+        seeds = [DAO_GOVERNANCE_SEED, b"treasury_proposal_counter"],
+        bump = treasury_proposal_counter.bump
+    )]
+    pub treasury_proposal_counter: Account<'info, ProposalCounter>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = TreasuryWithdrawalProposal::LEN,
+        seeds = [DAO_GOVERNANCE_SEED, b"treasury_proposal", treasury_proposal_counter.next_proposal_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Account<'info, TreasuryWithdrawalProposal>,
+
+    #[account(
+        seeds = [USER_PROFILE_SEED, proposer.key().as_ref()],
+        bump = proposer_profile.bump,
+        constraint = proposer_profile.owner == proposer.key() @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub proposer_profile: Account<'info, UserHealthProfile>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_treasury_withdrawal_proposal(
+    ctx: Context<CreateTreasuryWithdrawalProposal>,
+    recipient: Pubkey,
+    amount: u64,
+) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    let proposer = &ctx.accounts.proposer;
+
+    // The proposal PDA was derived from this ID, so it is taken atomically
+    let proposal_id = ctx.accounts.treasury_proposal_counter.assign_next();
+
+    **proposal = TreasuryWithdrawalProposal::new(
+        proposal_id,
+        proposer.key(),
+        recipient,
+        amount,
+        &ctx.accounts.config,
+        ctx.bumps.proposal,
+    )?;
+
+    // Emit event
+    emit!(TreasuryWithdrawalProposed {
+        proposal_id,
+        proposer: proposer.key(),
+        recipient,
+        amount,
+        expires_at: proposal.tally.expires_at,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Treasury withdrawal proposal {} created by: {}", proposal_id, proposer.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64, vote: bool)]
+pub struct VoteOnTreasuryWithdrawalProposal<'info> {
+    #[account(
+        mut,
+        seeds = [DAO_GOVERNANCE_SEED, b"treasury_proposal", proposal_id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, TreasuryWithdrawalProposal>,
+
+    #[account(
+        init,
+        payer = voter,
+        space = GovernanceVote::LEN,
+        seeds = [DAO_GOVERNANCE_SEED, b"treasury_vote", proposal_id.to_le_bytes().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub vote_record: Account<'info, GovernanceVote>,
+
+    #[account(
+        seeds = [USER_PROFILE_SEED, voter.key().as_ref()],
+        bump = voter_profile.bump,
+        constraint = voter_profile.owner == voter.key() @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub voter_profile: Account<'info, UserHealthProfile>,
+
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn vote_on_treasury_withdrawal_proposal(
+    ctx: Context<VoteOnTreasuryWithdrawalProposal>,
+    proposal_id: u64,
+    vote: bool,
+) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    let vote_record = &mut ctx.accounts.vote_record;
+    let voter = &ctx.accounts.voter;
+
+    // Cast the vote on the proposal
+    proposal.cast_vote(vote)?;
+
+    // Record the individual vote
+    **vote_record = GovernanceVote::new(
+        proposal_id,
+        voter.key(),
+        vote,
+        ctx.bumps.vote_record,
+    )?;
+
+    // Emit event
+    emit!(TreasuryWithdrawalVoteCast {
+        proposal_id,
+        voter: voter.key(),
+        vote,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Vote cast on treasury withdrawal proposal {} by: {}", proposal_id, voter.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct ExecuteTreasuryWithdrawalProposal<'info> {
+    #[account(
+        mut,
+        seeds = [DAO_GOVERNANCE_SEED, b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, GovernanceTreasury>,
+
+    #[account(
+        mut,
+        seeds = [DAO_GOVERNANCE_SEED, b"treasury_proposal", proposal_id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, TreasuryWithdrawalProposal>,
+
+    /// CHECK: Only receives lamports; must be the recipient named in the proposal
+    #[account(
+        mut,
+        address = proposal.recipient @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub recipient: UncheckedAccount<'info>,
+
+    pub executor: Signer<'info>,
+}
+
+pub fn execute_treasury_withdrawal_proposal(
+    ctx: Context<ExecuteTreasuryWithdrawalProposal>,
+    proposal_id: u64,
+) -> Result<()> {
+    let treasury = &mut ctx.accounts.treasury;
+    let proposal = &mut ctx.accounts.proposal;
+
+    // Only once voting has closed and the vote passed
+    proposal.execute(treasury)?;
+
+    // The treasury keeps its own rent-exempt minimum
+    let treasury_info = treasury.to_account_info();
+    let reserved = Rent::get()?.minimum_balance(GovernanceTreasury::LEN);
+    let available = treasury_info.lamports().saturating_sub(reserved);
+    require!(
+        proposal.amount <= available,
+        HealthManagerError::InsufficientTreasuryFunds
+    );
+
+    // The treasury is program-owned, so lamports move directly
+    **treasury_info.try_borrow_mut_lamports()? -= proposal.amount;
+    **ctx.accounts.recipient.try_borrow_mut_lamports()? += proposal.amount;
+
+    // Emit event
+    emit!(TreasuryWithdrawn {
+        proposal_id,
+        recipient: proposal.recipient,
+        amount: proposal.amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Treasury withdrawal proposal {} executed by: {}", proposal_id, ctx.accounts.executor.key());
+    Ok(())
+}
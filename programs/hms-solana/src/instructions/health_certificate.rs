@@ -0,0 +1,348 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::metadata::{
+    mpl_token_metadata::accounts::{MasterEdition, Metadata as MetadataPda},
+    Metadata,
+};
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::{
+    constants::*,
+    errors::HealthManagerError,
+    events::{
+        CertificateIssuerRegistered, CertificateIssuerRevoked, HealthCertificateIssued,
+        HealthCertificateRevoked,
+    },
+    instructions::HmsNftMintAccounts,
+    program::HmsSolana,
+    state::{
+        CertificateIssuer, CertificateStatus, HealthCertificate, HealthRecord, UserHealthProfile,
+    },
+};
+
+#[derive(Accounts)]
+#[instruction(issuer: Pubkey, name: String)]
+pub struct RegisterCertificateIssuer<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = CertificateIssuer::LEN,
+        seeds = [CERTIFICATE_ISSUER_SEED, issuer.as_ref()],
+        bump
+    )]
+    pub certificate_issuer: Account<'info, CertificateIssuer>,
+
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key()) @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub program: Program<'info, HmsSolana>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(authority.key()) @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    /// Only the program upgrade authority can register issuers
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn register_certificate_issuer(
+    ctx: Context<RegisterCertificateIssuer>,
+    issuer: Pubkey,
+    name: String,
+) -> Result<()> {
+    let certificate_issuer = &mut ctx.accounts.certificate_issuer;
+
+    **certificate_issuer = CertificateIssuer::new(
+        issuer,
+        name.clone(),
+        ctx.bumps.certificate_issuer,
+    )?;
+
+    // Emit event
+    emit!(CertificateIssuerRegistered {
+        issuer,
+        name,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Certificate issuer registered: {}", issuer);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(issuer: Pubkey)]
+pub struct RevokeCertificateIssuer<'info> {
+    #[account(
+        mut,
+        seeds = [CERTIFICATE_ISSUER_SEED, issuer.as_ref()],
+        bump = certificate_issuer.bump
+    )]
+    pub certificate_issuer: Account<'info, CertificateIssuer>,
+
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key()) @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub program: Program<'info, HmsSolana>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(authority.key()) @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn revoke_certificate_issuer(
+    ctx: Context<RevokeCertificateIssuer>,
+    issuer: Pubkey,
+) -> Result<()> {
+    let certificate_issuer = &mut ctx.accounts.certificate_issuer;
+
+    // Certificates from this issuer stop verifying immediately
+    certificate_issuer.revoke();
+
+    // Emit event
+    emit!(CertificateIssuerRevoked {
+        issuer,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Certificate issuer revoked: {}", issuer);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(record_id: u64)]
+pub struct IssueHealthCertificate<'info> {
+    #[account(
+        seeds = [CERTIFICATE_ISSUER_SEED, issuer.key().as_ref()],
+        bump = certificate_issuer.bump,
+        constraint = certificate_issuer.is_active @ HealthManagerError::IssuerNotRegistered
+    )]
+    pub certificate_issuer: Account<'info, CertificateIssuer>,
+
+    #[account(mut)]
+    pub issuer: Signer<'info>,
+
+    /// The patient consents to binding one of their records to the certificate
+    pub patient: Signer<'info>,
+
+    #[account(
+        seeds = [USER_PROFILE_SEED, patient.key().as_ref()],
+        bump = profile.bump,
+        constraint = profile.owner == patient.key() @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub profile: Account<'info, UserHealthProfile>,
+
+    #[account(
+        seeds = [HEALTH_RECORD_SEED, patient.key().as_ref(), record_id.to_le_bytes().as_ref()],
+        bump = record.bump,
+        constraint = record.owner == patient.key() @ HealthManagerError::UnauthorizedAccess,
+        constraint = record.is_accessible() @ HealthManagerError::RecordSoftDeleted
+    )]
+    pub record: Account<'info, HealthRecord>,
+
+    #[account(
+        init,
+        payer = issuer,
+        space = HealthCertificate::LEN,
+        seeds = [HEALTH_CERTIFICATE_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub certificate: Account<'info, HealthCertificate>,
+
+    /// CHECK: PDA signer acting as mint, update and collection authority
+    #[account(
+        seeds = [NFT_AUTHORITY_SEED],
+        bump
+    )]
+    pub nft_authority: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = issuer,
+        mint::decimals = 0,
+        mint::authority = nft_authority,
+        mint::freeze_authority = nft_authority,
+    )]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = issuer,
+        associated_token::mint = mint,
+        associated_token::authority = patient,
+    )]
+    pub token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Created by the token metadata program; address is checked
+    #[account(
+        mut,
+        address = MetadataPda::find_pda(&mint.key()).0,
+    )]
+    pub metadata_account: UncheckedAccount<'info>,
+
+    /// CHECK: Created by the token metadata program; address is checked
+    #[account(
+        mut,
+        address = MasterEdition::find_pda(&mint.key()).0,
+    )]
+    pub master_edition_account: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [HMS_COLLECTION_SEED],
+        bump
+    )]
+    pub collection_mint: Account<'info, Mint>,
+
+    /// CHECK: Collection metadata owned by the token metadata program; address is checked
+    #[account(
+        mut,
+        address = MetadataPda::find_pda(&collection_mint.key()).0,
+    )]
+    pub collection_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Collection master edition owned by the token metadata program; address is checked
+    #[account(
+        address = MasterEdition::find_pda(&collection_mint.key()).0,
+    )]
+    pub collection_master_edition: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn issue_health_certificate(
+    ctx: Context<IssueHealthCertificate>,
+    record_id: u64,
+    certificate_type: String,
+    expires_at: i64,
+    name: String,
+    symbol: String,
+    uri: String,
+) -> Result<()> {
+    let certificate = &mut ctx.accounts.certificate;
+    let record = &ctx.accounts.record;
+
+    **certificate = HealthCertificate::new(
+        ctx.accounts.mint.key(),
+        ctx.accounts.issuer.key(),
+        ctx.accounts.profile.key(),
+        ctx.accounts.patient.key(),
+        record.key(),
+        record.data_hash,
+        certificate_type,
+        expires_at,
+        ctx.bumps.certificate,
+    )?;
+
+    // Certificates are always soulbound to the patient's wallet
+    HmsNftMintAccounts {
+        payer: ctx.accounts.issuer.to_account_info(),
+        nft_authority: ctx.accounts.nft_authority.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        token_account: ctx.accounts.token_account.to_account_info(),
+        metadata: ctx.accounts.metadata_account.to_account_info(),
+        master_edition: ctx.accounts.master_edition_account.to_account_info(),
+        collection_mint: ctx.accounts.collection_mint.to_account_info(),
+        collection_metadata: ctx.accounts.collection_metadata.to_account_info(),
+        collection_master_edition: ctx.accounts.collection_master_edition.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        rent: ctx.accounts.rent.to_account_info(),
+    }
+    .mint(ctx.bumps.nft_authority, name, symbol, uri, true)?;
+
+    // Emit event
+    emit!(HealthCertificateIssued {
+        mint: certificate.mint,
+        issuer: certificate.issuer,
+        patient: certificate.patient,
+        record_id,
+        data_hash: certificate.data_hash,
+        certificate_type: certificate.certificate_type.clone(),
+        expires_at,
+        timestamp: certificate.issued_at,
+    });
+
+    msg!("Health certificate {} issued to: {}", certificate.mint, certificate.patient);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(mint: Pubkey)]
+pub struct RevokeHealthCertificate<'info> {
+    #[account(
+        mut,
+        seeds = [HEALTH_CERTIFICATE_SEED, mint.as_ref()],
+        bump = certificate.bump,
+        constraint = certificate.issuer == issuer.key() @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub certificate: Account<'info, HealthCertificate>,
+
+    pub issuer: Signer<'info>,
+}
+
+pub fn revoke_health_certificate(
+    ctx: Context<RevokeHealthCertificate>,
+    mint: Pubkey,
+) -> Result<()> {
+    let certificate = &mut ctx.accounts.certificate;
+    let issuer = &ctx.accounts.issuer;
+
+    // Revoked certificates are kept on-chain so verifiers see why they fail
+    certificate.revoke()?;
+
+    // Emit event
+    emit!(HealthCertificateRevoked {
+        mint,
+        issuer: issuer.key(),
+        timestamp: certificate.revoked_at,
+    });
+
+    msg!("Health certificate {} revoked by: {}", mint, issuer.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(mint: Pubkey)]
+pub struct VerifyCertificate<'info> {
+    #[account(
+        seeds = [HEALTH_CERTIFICATE_SEED, mint.as_ref()],
+        bump = certificate.bump
+    )]
+    pub certificate: Account<'info, HealthCertificate>,
+
+    #[account(
+        seeds = [CERTIFICATE_ISSUER_SEED, certificate.issuer.as_ref()],
+        bump = certificate_issuer.bump
+    )]
+    pub certificate_issuer: Account<'info, CertificateIssuer>,
+
+    /// Record the certificate attests to
+    #[account(
+        address = certificate.record
+    )]
+    pub record: Account<'info, HealthRecord>,
+}
+
+pub fn verify_certificate(
+    ctx: Context<VerifyCertificate>,
+    mint: Pubkey,
+) -> Result<CertificateStatus> {
+    let status = ctx.accounts.certificate.status(
+        &ctx.accounts.certificate_issuer,
+        &ctx.accounts.record,
+    )?;
+
+    msg!("Health certificate {} valid: {}", mint, status.is_valid);
+    Ok(status)
+}
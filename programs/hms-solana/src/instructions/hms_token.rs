@@ -0,0 +1,190 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+use crate::{
+    constants::*,
+    errors::HealthManagerError,
+    events::{DataContributionRewarded, HmsTokenInitialized},
+    program::HmsSolana,
+    state::{ContributionReward, HealthRecord, HmsTokenConfig},
+};
+
+#[derive(Accounts)]
+#[instruction(decimals: u8)]
+pub struct InitializeHmsToken<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = HmsTokenConfig::LEN,
+        seeds = [HMS_TOKEN_SEED, b"config"],
+        bump
+    )]
+    pub config: Account<'info, HmsTokenConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        seeds = [HMS_TOKEN_SEED, b"mint"],
+        bump,
+        mint::decimals = decimals,
+        mint::authority = config,
+    )]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key()) @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub program: Program<'info, HmsSolana>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(authority.key()) @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    /// Only the program upgrade authority can create the token
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_hms_token(
+    ctx: Context<InitializeHmsToken>,
+    decimals: u8,
+    reward_per_contribution: u64,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let authority = &ctx.accounts.authority;
+
+    **config = HmsTokenConfig::new(
+        ctx.accounts.mint.key(),
+        authority.key(),
+        reward_per_contribution,
+        ctx.bumps.config,
+    )?;
+
+    // Emit event
+    emit!(HmsTokenInitialized {
+        mint: config.mint,
+        authority: authority.key(),
+        decimals,
+        reward_per_contribution,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("HMS token initialized: {}", config.mint);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(record_id: u64)]
+pub struct RewardDataContribution<'info> {
+    #[account(
+        mut,
+        seeds = [HMS_TOKEN_SEED, b"config"],
+        bump = config.bump,
+        constraint = config.authority == rewarder.key() @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub config: Account<'info, HmsTokenConfig>,
+
+    #[account(
+        mut,
+        address = config.mint
+    )]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        seeds = [HEALTH_RECORD_SEED, contributor.key().as_ref(), record_id.to_le_bytes().as_ref()],
+        bump = record.bump,
+        constraint = record.owner == contributor.key() @ HealthManagerError::UnauthorizedAccess,
+        constraint = record.is_accessible() @ HealthManagerError::RecordSoftDeleted
+    )]
+    pub record: Account<'info, HealthRecord>,
+
+    /// Each record can only be rewarded once
+    #[account(
+        init,
+        payer = rewarder,
+        space = ContributionReward::LEN,
+        seeds = [HMS_TOKEN_SEED, b"reward", record.key().as_ref()],
+        bump
+    )]
+    pub reward: Account<'info, ContributionReward>,
+
+    /// CHECK: Owner of the record, validated in constraints
+    pub contributor: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = contributor,
+    )]
+    pub contributor_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub rewarder: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn reward_data_contribution(
+    ctx: Context<RewardDataContribution>,
+    record_id: u64,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let reward = &mut ctx.accounts.reward;
+    let contributor = &ctx.accounts.contributor;
+    let amount = config.reward_per_contribution;
+
+    **reward = ContributionReward::new(
+        ctx.accounts.record.key(),
+        contributor.key(),
+        amount,
+        ctx.bumps.reward,
+    )?;
+
+    mint_hms_reward(
+        config,
+        &ctx.accounts.mint,
+        &ctx.accounts.contributor_token_account,
+        &ctx.accounts.token_program,
+        amount,
+    )?;
+
+    // Emit event
+    emit!(DataContributionRewarded {
+        contributor: contributor.key(),
+        record_id,
+        amount,
+        timestamp: reward.rewarded_at,
+    });
+
+    msg!("Record {} of {} rewarded with {} HMS", record_id, contributor.key(), amount);
+    Ok(())
+}
+
+/// Mint HMS to a contributor; the config PDA is the mint authority
+pub(crate) fn mint_hms_reward<'info>(
+    config: &mut Account<'info, HmsTokenConfig>,
+    mint: &Account<'info, Mint>,
+    to: &Account<'info, TokenAccount>,
+    token_program: &Program<'info, Token>,
+    amount: u64,
+) -> Result<()> {
+    let signer_seeds: &[&[&[u8]]] = &[&[HMS_TOKEN_SEED, b"config", &[config.bump]]];
+    let cpi_accounts = token::MintTo {
+        mint: mint.to_account_info(),
+        to: to.to_account_info(),
+        authority: config.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    token::mint_to(cpi_ctx, amount)?;
+
+    config.record_reward(amount);
+    Ok(())
+}
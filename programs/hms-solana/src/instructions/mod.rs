@@ -4,15 +4,22 @@ pub mod update_record;
 pub mod delete_record;
 pub mod grant_access;
 pub mod revoke_access;
+pub mod access_pricing;
+pub mod collection_access;
 pub mod read_record;
 pub mod zk_proof;
 pub mod emergency_access;
 pub mod dao_governance;
+pub mod governance_config;
+pub mod researcher_credential;
+pub mod research_compensation;
 pub mod wearable_integration;
 pub mod cross_device_sync;
 pub mod create_hms_nft;
-pub mod erc20;
-pub mod erc721;
+pub mod health_certificate;
+pub mod hms_token;
+pub mod access_ticket;
+pub mod contribution_rewards;
 
 pub use init_profile::*;
 pub use add_record::*;
@@ -20,12 +27,19 @@ pub use update_record::*;
 pub use delete_record::*;
 pub use grant_access::*;
 pub use revoke_access::*;
+pub use access_pricing::*;
+pub use collection_access::*;
 pub use read_record::*;
 pub use zk_proof::*;
 pub use emergency_access::*;
 pub use dao_governance::*;
+pub use governance_config::*;
+pub use researcher_credential::*;
+pub use research_compensation::*;
 pub use wearable_integration::*;
 pub use cross_device_sync::*;
 pub use create_hms_nft::*;
-pub use erc20::*;
-pub use erc721::*;
\ No newline at end of file
+pub use health_certificate::*;
+pub use hms_token::*;
+pub use access_ticket::*;
+pub use contribution_rewards::*;
\ No newline at end of file
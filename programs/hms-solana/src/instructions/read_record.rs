@@ -1,9 +1,12 @@
 use anchor_lang::prelude::*;
+use anchor_spl::metadata::MetadataAccount;
+use anchor_spl::token::{Token, TokenAccount};
 use crate::{
     constants::*,
     errors::HealthManagerError,
-    events::AuthorizedRecordAccess,
-    state::{UserHealthProfile, HealthRecord, AccessGrant},
+    events::{AccessPricePaid, AuthorizedRecordAccess},
+    state::{UserHealthProfile, HealthRecord, AccessGrant, CollectionAccessGrant, AccessTicket},
+    instructions::{collect_access_payment, verify_collection_access, verify_ticket_access},
 };
 
 #[derive(Accounts)]
@@ -24,8 +27,11 @@ pub struct ReadRecord<'info> {
     pub record: Account<'info, HealthRecord>,
 
     /// CHECK: This is the owner of the record, validated in constraints
+    #[account(mut)]
     pub record_owner: UncheckedAccount<'info>,
 
+    /// Mutable so a priced grant can be paid for in the same instruction
+    #[account(mut)]
     pub accessor: Signer<'info>,
 
     /// Optional access grant account for authorized users
@@ -34,32 +40,133 @@ pub struct ReadRecord<'info> {
         bump = access_grant.bump,
     )]
     pub access_grant: Option<Account<'info, AccessGrant>>,
+
+    /// Optional collection grant, used when the accessor holds a credential NFT
+    #[account(
+        seeds = [COLLECTION_GRANT_SEED, record_owner.key().as_ref(), collection_grant.collection.as_ref()],
+        bump = collection_grant.bump,
+    )]
+    pub collection_grant: Option<Account<'info, CollectionAccessGrant>>,
+
+    /// Credential NFT token account held by the accessor
+    pub credential_token: Option<Account<'info, TokenAccount>>,
+
+    /// Metadata of the credential NFT
+    pub credential_metadata: Option<Account<'info, MetadataAccount>>,
+
+    /// CHECK: Mint of the HMS passport collection, required with a collection grant; only its address is used
+    #[account(
+        seeds = [HMS_COLLECTION_SEED],
+        bump
+    )]
+    pub hms_collection: Option<UncheckedAccount<'info>>,
+
+    /// Optional access ticket held by the accessor
+    #[account(
+        seeds = [ACCESS_TICKET_SEED, access_ticket.mint.as_ref()],
+        bump = access_ticket.bump,
+    )]
+    pub access_ticket: Option<Account<'info, AccessTicket>>,
+
+    /// Token account holding the access ticket
+    pub ticket_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Accessor's token account, required when the grant is priced in an SPL token
+    #[account(mut)]
+    pub accessor_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Owner's token account, required when the grant is priced in an SPL token
+    #[account(mut)]
+    pub owner_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
 }
 
 pub fn read_record(
     ctx: Context<ReadRecord>,
     _record_id: u64,
+    max_price: u64,
+    expected_mint: Option<Pubkey>,
 ) -> Result<()> {
     let record = &ctx.accounts.record;
     let accessor = &ctx.accounts.accessor;
     let record_owner = &ctx.accounts.record_owner;
 
     // Check if accessor is owner or has valid read access
-    if accessor.key() != record_owner.key() {
-        let access_grant = ctx.accounts.access_grant.as_ref()
-            .ok_or(HealthManagerError::UnauthorizedAccess)?;
+    let via_collection = if accessor.key() != record_owner.key() {
+        if let Some(access_grant) = ctx.accounts.access_grant.as_ref() {
+            require!(
+                access_grant.has_permission(PERMISSION_READ),
+                HealthManagerError::InsufficientPermissions
+            );
+
+            // Priced grants are paid atomically with the read
+            if let Some(price) = access_grant.price.as_ref() {
+                if price.is_per_read() {
+                    price.check_limit(max_price, expected_mint)?;
+                    collect_access_payment(
+                        price,
+                        accessor.to_account_info(),
+                        record_owner.to_account_info(),
+                        ctx.accounts.accessor_token_account.as_ref(),
+                        ctx.accounts.owner_token_account.as_ref(),
+                        ctx.accounts.system_program.to_account_info(),
+                        ctx.accounts.token_program.to_account_info(),
+                    )?;
+
+                    emit!(AccessPricePaid {
+                        owner: record_owner.key(),
+                        grantee: accessor.key(),
+                        amount: price.amount,
+                        mint: price.mint,
+                        paid_until: 0,
+                        timestamp: Clock::get()?.unix_timestamp,
+                    });
+                } else {
+                    require!(
+                        access_grant.is_paid_up(),
+                        HealthManagerError::AccessPaymentRequired
+                    );
+                }
+            }
+            None
+        } else if let Some(access_ticket) = ctx.accounts.access_ticket.as_ref() {
+            let ticket_token_account = ctx.accounts.ticket_token_account.as_ref()
+                .ok_or(HealthManagerError::NotTicketHolder)?;
+            verify_ticket_access(access_ticket, ticket_token_account, accessor.key(), record, PERMISSION_READ)?;
+            None
+        } else {
+            // Fall back to a grant for holders of a credential NFT collection
+            let collection_grant = ctx.accounts.collection_grant.as_ref()
+                .ok_or(HealthManagerError::UnauthorizedAccess)?;
+            let credential_token = ctx.accounts.credential_token.as_ref()
+                .ok_or(HealthManagerError::InvalidCredentialToken)?;
+            let credential_metadata = ctx.accounts.credential_metadata.as_ref()
+                .ok_or(HealthManagerError::CredentialNotInCollection)?;
+            let hms_collection = ctx.accounts.hms_collection.as_ref()
+                .ok_or(HealthManagerError::UnauthorizedAccess)?;
 
-        require!(
-            access_grant.has_permission(PERMISSION_READ),
-            HealthManagerError::InsufficientPermissions
-        );
-    }
+            verify_collection_access(
+                collection_grant,
+                hms_collection.key(),
+                accessor.key(),
+                credential_token,
+                credential_metadata,
+                PERMISSION_READ,
+            )?;
+            Some(collection_grant.collection)
+        }
+    } else {
+        None
+    };
 
     // Emit event for audit trail
     emit!(AuthorizedRecordAccess {
         owner: record_owner.key(),
         record_id: record.id,
         accessor: accessor.key(),
+        via_collection,
         timestamp: Clock::get()?.unix_timestamp,
     });
 
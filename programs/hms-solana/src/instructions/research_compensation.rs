@@ -0,0 +1,304 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+use crate::{
+    constants::*,
+    errors::HealthManagerError,
+    events::{
+        ResearchCompensationFunded, ResearchCompensationClaimed, ResearchCompensationRefunded,
+    },
+    state::{ResearchCompensation, ResearchConsent, ResearchProposal},
+};
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct FundResearchCompensation<'info> {
+    /// Funding is only possible before voting starts, so voters and data
+    /// owners know the compensation terms up front
+    #[account(
+        seeds = [DAO_GOVERNANCE_SEED, b"proposal", proposal_id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        constraint = proposal.researcher == researcher.key() @ HealthManagerError::UnauthorizedAccess,
+        constraint = !proposal.is_finalized() @ HealthManagerError::ResearchProposalFinalized,
+        constraint = proposal.total_voters == 0 @ HealthManagerError::ResearchProposalHasVotes
+    )]
+    pub proposal: Account<'info, ResearchProposal>,
+
+    #[account(
+        init,
+        payer = researcher,
+        space = ResearchCompensation::LEN,
+        seeds = [DAO_GOVERNANCE_SEED, b"compensation", proposal_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub compensation: Account<'info, ResearchCompensation>,
+
+    #[account(
+        init,
+        payer = researcher,
+        seeds = [DAO_GOVERNANCE_SEED, b"compensation_vault", proposal_id.to_le_bytes().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = compensation,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = researcher,
+    )]
+    pub researcher_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub researcher: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn fund_research_compensation(
+    ctx: Context<FundResearchCompensation>,
+    proposal_id: u64,
+    amount: u64,
+    payout_per_record: u64,
+    payout_per_participant: u64,
+) -> Result<()> {
+    let compensation = &mut ctx.accounts.compensation;
+    let researcher = &ctx.accounts.researcher;
+
+    **compensation = ResearchCompensation::new(
+        proposal_id,
+        researcher.key(),
+        ctx.accounts.mint.key(),
+        ctx.accounts.vault.key(),
+        amount,
+        payout_per_record,
+        payout_per_participant,
+        ctx.accounts.proposal.scope.access_expires_at,
+        ctx.bumps.compensation,
+    )?;
+
+    // Move the funds into escrow
+    let cpi_accounts = token::Transfer {
+        from: ctx.accounts.researcher_token_account.to_account_info(),
+        to: ctx.accounts.vault.to_account_info(),
+        authority: researcher.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_ctx, amount)?;
+
+    // Emit event
+    emit!(ResearchCompensationFunded {
+        proposal_id,
+        researcher: researcher.key(),
+        mint: ctx.accounts.mint.key(),
+        amount,
+        payout_per_record,
+        payout_per_participant,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Research compensation for proposal {} funded by: {}", proposal_id, researcher.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct ClaimResearchCompensation<'info> {
+    #[account(
+        mut,
+        seeds = [DAO_GOVERNANCE_SEED, b"compensation", proposal_id.to_le_bytes().as_ref()],
+        bump = compensation.bump
+    )]
+    pub compensation: Account<'info, ResearchCompensation>,
+
+    #[account(
+        mut,
+        address = compensation.vault
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [DAO_GOVERNANCE_SEED, b"consent", proposal_id.to_le_bytes().as_ref(), owner.key().as_ref()],
+        bump = consent.bump,
+        constraint = consent.owner == owner.key() @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub consent: Account<'info, ResearchConsent>,
+
+    #[account(
+        mut,
+        token::mint = compensation.mint,
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn claim_research_compensation(
+    ctx: Context<ClaimResearchCompensation>,
+    proposal_id: u64,
+) -> Result<()> {
+    let compensation = &mut ctx.accounts.compensation;
+    let consent = &mut ctx.accounts.consent;
+    let owner = &ctx.accounts.owner;
+
+    // Only accesses that actually happened are paid for
+    let amount = compensation.amount_owed(
+        consent.records_accessed,
+        consent.records_compensated,
+        consent.participant_compensated,
+    )?;
+    require!(amount > 0, HealthManagerError::NothingToClaim);
+    require!(
+        ctx.accounts.vault.amount >= amount,
+        HealthManagerError::InsufficientCompensationFunds
+    );
+
+    let proposal_id_bytes = proposal_id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        DAO_GOVERNANCE_SEED,
+        b"compensation",
+        proposal_id_bytes.as_ref(),
+        &[compensation.bump],
+    ]];
+    let cpi_accounts = token::Transfer {
+        from: ctx.accounts.vault.to_account_info(),
+        to: ctx.accounts.owner_token_account.to_account_info(),
+        authority: compensation.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    token::transfer(cpi_ctx, amount)?;
+
+    consent.mark_compensated();
+    compensation.record_payout(amount);
+
+    // Emit event
+    emit!(ResearchCompensationClaimed {
+        proposal_id,
+        data_owner: owner.key(),
+        records_compensated: consent.records_compensated,
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Research compensation for proposal {} claimed by: {}", proposal_id, owner.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct RefundResearchCompensation<'info> {
+    /// CHECK: May already be closed; deserialized in the handler when it still exists
+    #[account(
+        seeds = [DAO_GOVERNANCE_SEED, b"proposal", proposal_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = researcher,
+        seeds = [DAO_GOVERNANCE_SEED, b"compensation", proposal_id.to_le_bytes().as_ref()],
+        bump = compensation.bump,
+        constraint = compensation.researcher == researcher.key() @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub compensation: Account<'info, ResearchCompensation>,
+
+    #[account(
+        mut,
+        address = compensation.vault
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = compensation.mint,
+    )]
+    pub researcher_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub researcher: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn refund_research_compensation(
+    ctx: Context<RefundResearchCompensation>,
+    proposal_id: u64,
+) -> Result<()> {
+    let proposal_info = &ctx.accounts.proposal;
+    let compensation = &ctx.accounts.compensation;
+    let researcher = &ctx.accounts.researcher;
+
+    // A proposal that was never approved owes nothing; otherwise data owners
+    // get until the claim deadline to collect their payouts
+    let never_approved = if proposal_info.data_is_empty() {
+        false
+    } else {
+        let data = proposal_info.try_borrow_data()?;
+        let proposal = ResearchProposal::try_deserialize(&mut &data[..])?;
+        proposal.is_finalized() && !proposal.is_approved
+    };
+    require!(
+        never_approved || compensation.is_claim_window_over(),
+        HealthManagerError::CompensationClaimWindowOpen
+    );
+
+    let remaining = ctx.accounts.vault.amount;
+    let proposal_id_bytes = proposal_id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        DAO_GOVERNANCE_SEED,
+        b"compensation",
+        proposal_id_bytes.as_ref(),
+        &[compensation.bump],
+    ]];
+
+    if remaining > 0 {
+        let cpi_accounts = token::Transfer {
+            from: ctx.accounts.vault.to_account_info(),
+            to: ctx.accounts.researcher_token_account.to_account_info(),
+            authority: compensation.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        token::transfer(cpi_ctx, remaining)?;
+    }
+
+    // Close the empty vault and return its rent
+    let cpi_accounts = token::CloseAccount {
+        account: ctx.accounts.vault.to_account_info(),
+        destination: researcher.to_account_info(),
+        authority: compensation.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    token::close_account(cpi_ctx)?;
+
+    // Emit event
+    emit!(ResearchCompensationRefunded {
+        proposal_id,
+        researcher: researcher.key(),
+        amount: remaining,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Research compensation for proposal {} refunded to: {}", proposal_id, researcher.key());
+
+    // Compensation account will be closed automatically due to close constraint
+    Ok(())
+}
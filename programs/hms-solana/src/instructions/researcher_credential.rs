@@ -0,0 +1,257 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    errors::HealthManagerError,
+    events::{
+        AttestorRegistered, AttestorRevoked, ResearcherCredentialIssued,
+        ResearcherCredentialRevoked,
+    },
+    program::HmsSolana,
+    state::{ResearchAttestor, ResearcherCredential},
+};
+
+#[derive(Accounts)]
+#[instruction(attestor: Pubkey, name: String)]
+pub struct RegisterResearchAttestor<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = ResearchAttestor::LEN,
+        seeds = [RESEARCH_ATTESTOR_SEED, attestor.as_ref()],
+        bump
+    )]
+    pub research_attestor: Account<'info, ResearchAttestor>,
+
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key()) @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub program: Program<'info, HmsSolana>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(authority.key()) @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    /// Only the program upgrade authority can register attestors
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn register_research_attestor(
+    ctx: Context<RegisterResearchAttestor>,
+    attestor: Pubkey,
+    name: String,
+) -> Result<()> {
+    let research_attestor = &mut ctx.accounts.research_attestor;
+
+    **research_attestor = ResearchAttestor::new(
+        attestor,
+        name.clone(),
+        ctx.bumps.research_attestor,
+    )?;
+
+    // Emit event
+    emit!(AttestorRegistered {
+        attestor,
+        name,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Research attestor registered: {}", attestor);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(attestor: Pubkey)]
+pub struct RevokeResearchAttestor<'info> {
+    #[account(
+        mut,
+        seeds = [RESEARCH_ATTESTOR_SEED, attestor.as_ref()],
+        bump = research_attestor.bump
+    )]
+    pub research_attestor: Account<'info, ResearchAttestor>,
+
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key()) @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub program: Program<'info, HmsSolana>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(authority.key()) @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn revoke_research_attestor(
+    ctx: Context<RevokeResearchAttestor>,
+    attestor: Pubkey,
+) -> Result<()> {
+    let research_attestor = &mut ctx.accounts.research_attestor;
+
+    // Credentials issued by this attestor stop being accepted immediately
+    research_attestor.revoke();
+
+    // Emit event
+    emit!(AttestorRevoked {
+        attestor,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Research attestor revoked: {}", attestor);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(researcher: Pubkey)]
+pub struct IssueResearcherCredential<'info> {
+    #[account(
+        seeds = [RESEARCH_ATTESTOR_SEED, attestor.key().as_ref()],
+        bump = research_attestor.bump,
+        constraint = research_attestor.is_active @ HealthManagerError::AttestorNotTrusted
+    )]
+    pub research_attestor: Account<'info, ResearchAttestor>,
+
+    #[account(
+        init,
+        payer = attestor,
+        space = ResearcherCredential::LEN,
+        seeds = [RESEARCHER_CREDENTIAL_SEED, researcher.as_ref()],
+        bump
+    )]
+    pub credential: Account<'info, ResearcherCredential>,
+
+    #[account(mut)]
+    pub attestor: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn issue_researcher_credential(
+    ctx: Context<IssueResearcherCredential>,
+    researcher: Pubkey,
+    institution: String,
+    irb_approval_hash: [u8; 32],
+    expires_at: i64,
+) -> Result<()> {
+    let credential = &mut ctx.accounts.credential;
+    let attestor = &ctx.accounts.attestor;
+
+    **credential = ResearcherCredential::new(
+        researcher,
+        attestor.key(),
+        institution.clone(),
+        irb_approval_hash,
+        expires_at,
+        ctx.bumps.credential,
+    )?;
+
+    // Emit event
+    emit!(ResearcherCredentialIssued {
+        researcher,
+        attestor: attestor.key(),
+        institution,
+        irb_approval_hash,
+        expires_at,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Researcher credential issued to {} by: {}", researcher, attestor.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(researcher: Pubkey)]
+pub struct RevokeResearcherCredential<'info> {
+    #[account(
+        mut,
+        seeds = [RESEARCHER_CREDENTIAL_SEED, researcher.as_ref()],
+        bump = credential.bump,
+        constraint = credential.attestor == attestor.key() @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub credential: Account<'info, ResearcherCredential>,
+
+    pub attestor: Signer<'info>,
+}
+
+pub fn revoke_researcher_credential(
+    ctx: Context<RevokeResearcherCredential>,
+    researcher: Pubkey,
+) -> Result<()> {
+    let credential = &mut ctx.accounts.credential;
+    let attestor = &ctx.accounts.attestor;
+
+    // Revoked credentials are kept on-chain for the audit trail
+    credential.revoke()?;
+
+    // Emit event
+    emit!(ResearcherCredentialRevoked {
+        researcher,
+        attestor: attestor.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Researcher credential of {} revoked by: {}", researcher, attestor.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(researcher: Pubkey)]
+pub struct RenewResearcherCredential<'info> {
+    #[account(
+        seeds = [RESEARCH_ATTESTOR_SEED, attestor.key().as_ref()],
+        bump = research_attestor.bump,
+        constraint = research_attestor.is_active @ HealthManagerError::AttestorNotTrusted
+    )]
+    pub research_attestor: Account<'info, ResearchAttestor>,
+
+    /// Valid or revoked credentials can only be renewed by the attestor that
+    /// issued them; expired ones by any active attestor
+    #[account(
+        mut,
+        seeds = [RESEARCHER_CREDENTIAL_SEED, researcher.as_ref()],
+        bump = credential.bump,
+        constraint = credential.can_be_renewed_by(attestor.key()) @ HealthManagerError::UnauthorizedAccess
+    )]
+    pub credential: Account<'info, ResearcherCredential>,
+
+    pub attestor: Signer<'info>,
+}
+
+pub fn renew_researcher_credential(
+    ctx: Context<RenewResearcherCredential>,
+    researcher: Pubkey,
+    institution: String,
+    irb_approval_hash: [u8; 32],
+    expires_at: i64,
+) -> Result<()> {
+    let credential = &mut ctx.accounts.credential;
+    let attestor = &ctx.accounts.attestor;
+    let bump = credential.bump;
+
+    // Reissue in place so expired or revoked researchers can be credentialed again
+    **credential = ResearcherCredential::new(
+        researcher,
+        attestor.key(),
+        institution.clone(),
+        irb_approval_hash,
+        expires_at,
+        bump,
+    )?;
+
+    // Emit event
+    emit!(ResearcherCredentialIssued {
+        researcher,
+        attestor: attestor.key(),
+        institution,
+        irb_approval_hash,
+        expires_at,
+        timestamp: credential.issued_at,
+    });
+
+    msg!("Researcher credential of {} renewed by: {}", researcher, attestor.key());
+    Ok(())
+}
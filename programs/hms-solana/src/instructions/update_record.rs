@@ -1,9 +1,12 @@
 use anchor_lang::prelude::*;
+use anchor_spl::metadata::MetadataAccount;
+use anchor_spl::token::TokenAccount;
 use crate::{
     constants::*,
     errors::HealthManagerError,
     events::HealthRecordUpdated,
-    state::{UserHealthProfile, HealthRecord, AccessGrant},
+    state::{UserHealthProfile, HealthRecord, AccessGrant, CollectionAccessGrant, AccessTicket},
+    instructions::{verify_collection_access, verify_ticket_access},
 };
 
 #[derive(Accounts)]
@@ -36,6 +39,36 @@ pub struct UpdateRecord<'info> {
         bump = access_grant.bump,
     )]
     pub access_grant: Option<Account<'info, AccessGrant>>,
+
+    /// Optional collection grant, used when the actor holds a credential NFT
+    #[account(
+        seeds = [COLLECTION_GRANT_SEED, record_owner.key().as_ref(), collection_grant.collection.as_ref()],
+        bump = collection_grant.bump,
+    )]
+    pub collection_grant: Option<Account<'info, CollectionAccessGrant>>,
+
+    /// Credential NFT token account held by the actor
+    pub credential_token: Option<Account<'info, TokenAccount>>,
+
+    /// Metadata of the credential NFT
+    pub credential_metadata: Option<Account<'info, MetadataAccount>>,
+
+    /// CHECK: Mint of the HMS passport collection, required with a collection grant; only its address is used
+    #[account(
+        seeds = [HMS_COLLECTION_SEED],
+        bump
+    )]
+    pub hms_collection: Option<UncheckedAccount<'info>>,
+
+    /// Optional access ticket held by the actor
+    #[account(
+        seeds = [ACCESS_TICKET_SEED, access_ticket.mint.as_ref()],
+        bump = access_ticket.bump,
+    )]
+    pub access_ticket: Option<Account<'info, AccessTicket>>,
+
+    /// Token account holding the access ticket
+    pub ticket_token_account: Option<Account<'info, TokenAccount>>,
 }
 
 pub fn update_record(
@@ -49,13 +82,44 @@ pub fn update_record(
 
     // Check if actor is owner or has valid access
     if actor.key() != record_owner.key() {
-        let access_grant = ctx.accounts.access_grant.as_ref()
-            .ok_or(HealthManagerError::UnauthorizedAccess)?;
+        if let Some(access_grant) = ctx.accounts.access_grant.as_ref() {
+            require!(
+                access_grant.has_permission(PERMISSION_WRITE),
+                HealthManagerError::InsufficientPermissions
+            );
+
+            // A per-read price pays for the owner's data, and a write only
+            // replaces metadata without returning any, so it is not charged.
+            // A periodic price must still be paid up before the grantee can write
+            let is_periodic = access_grant.price.as_ref().is_some_and(|p| !p.is_per_read());
+            require!(
+                !is_periodic || access_grant.is_paid_up(),
+                HealthManagerError::AccessPaymentRequired
+            );
+        } else if let Some(access_ticket) = ctx.accounts.access_ticket.as_ref() {
+            let ticket_token_account = ctx.accounts.ticket_token_account.as_ref()
+                .ok_or(HealthManagerError::NotTicketHolder)?;
+            verify_ticket_access(access_ticket, ticket_token_account, actor.key(), record, PERMISSION_WRITE)?;
+        } else {
+            // Fall back to a grant for holders of a credential NFT collection
+            let collection_grant = ctx.accounts.collection_grant.as_ref()
+                .ok_or(HealthManagerError::UnauthorizedAccess)?;
+            let credential_token = ctx.accounts.credential_token.as_ref()
+                .ok_or(HealthManagerError::InvalidCredentialToken)?;
+            let credential_metadata = ctx.accounts.credential_metadata.as_ref()
+                .ok_or(HealthManagerError::CredentialNotInCollection)?;
+            let hms_collection = ctx.accounts.hms_collection.as_ref()
+                .ok_or(HealthManagerError::UnauthorizedAccess)?;
 
-        require!(
-            access_grant.has_permission(PERMISSION_WRITE),
-            HealthManagerError::InsufficientPermissions
-        );
+            verify_collection_access(
+                collection_grant,
+                hms_collection.key(),
+                actor.key(),
+                credential_token,
+                credential_metadata,
+                PERMISSION_WRITE,
+            )?;
+        }
     }
 
     // Update the record metadata
@@ -101,6 +101,7 @@ pub struct IngestWearableData<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn ingest_wearable_data(
     ctx: Context<IngestWearableData>,
     device_id: String,
@@ -158,6 +158,7 @@ pub fn access_with_zk_proof(
         owner: record_owner.key(),
         record_id,
         accessor: accessor.key(),
+        via_collection: None,
         timestamp: Clock::get()?.unix_timestamp,
     });
 
@@ -20,14 +20,36 @@ pub mod hms_solana {
         instructions::initialize_profile(ctx)
     }
 
+    /// Create the program-owned collection that every HMS NFT is verified into
+    pub fn initialize_hms_collection(
+        ctx: Context<InitializeHmsCollection>,
+        name: String,
+        symbol: String,
+        uri: String,
+    ) -> Result<()> {
+        instructions::initialize_hms_collection(ctx, name, symbol, uri)
+    }
+
     /// Create a new HMS NFT
     pub fn create_hms_nft(
         ctx: Context<CreateHmsNft>,
         name: String,
         symbol: String,
         uri: String,
+        purpose: String,
+        soulbound: bool,
+    ) -> Result<()> {
+        instructions::create_hms_nft(ctx, name, symbol, uri, purpose, soulbound)
+    }
+
+    /// Re-issue an HMS NFT to a new wallet, marking the old one as superseded
+    pub fn reissue_hms_nft(
+        ctx: Context<ReissueHmsNft>,
+        name: String,
+        symbol: String,
+        uri: String,
     ) -> Result<()> {
-        instructions::create_hms_nft(ctx, name, symbol, uri)
+        instructions::reissue_hms_nft(ctx, name, symbol, uri)
     }
 
     /// Add a new health record
@@ -76,12 +98,32 @@ pub mod hms_solana {
         instructions::revoke_access(ctx, grantee)
     }
 
-    /// Read a health record (with access control)
+    /// Grant access to holders of a verified credential NFT collection
+    pub fn grant_collection_access(
+        ctx: Context<GrantCollectionAccess>,
+        collection: Pubkey,
+        expires_at: i64,
+        permissions: u8,
+    ) -> Result<()> {
+        instructions::grant_collection_access(ctx, collection, expires_at, permissions)
+    }
+
+    /// Revoke access from holders of a credential NFT collection
+    pub fn revoke_collection_access(
+        ctx: Context<RevokeCollectionAccess>,
+        collection: Pubkey,
+    ) -> Result<()> {
+        instructions::revoke_collection_access(ctx, collection)
+    }
+
+    /// Read a health record (with access control), paying at most `max_price` if priced per read
     pub fn read_record(
         ctx: Context<ReadRecord>,
         record_id: u64,
+        max_price: u64,
+        expected_mint: Option<Pubkey>,
     ) -> Result<()> {
-        instructions::read_record(ctx, record_id)
+        instructions::read_record(ctx, record_id, max_price, expected_mint)
     }
 
     // ZK Proof Instructions
@@ -148,13 +190,99 @@ pub mod hms_solana {
     }
 
     // DAO Governance Instructions
+    /// Initialize governance parameters (program upgrade authority only)
+    pub fn initialize_governance_config(
+        ctx: Context<InitializeGovernanceConfig>,
+        quorum_votes: u64,
+        approval_threshold_bps: u16,
+        voting_period: i64,
+        execution_delay: i64,
+        proposal_deposit: u64,
+    ) -> Result<()> {
+        instructions::initialize_governance_config(
+            ctx,
+            quorum_votes,
+            approval_threshold_bps,
+            voting_period,
+            execution_delay,
+            proposal_deposit,
+        )
+    }
+
+    /// Propose a change to the governance parameters
+    pub fn create_parameter_proposal(
+        ctx: Context<CreateParameterProposal>,
+        quorum_votes: u64,
+        approval_threshold_bps: u16,
+        voting_period: i64,
+        execution_delay: i64,
+        proposal_deposit: u64,
+    ) -> Result<()> {
+        instructions::create_parameter_proposal(
+            ctx,
+            quorum_votes,
+            approval_threshold_bps,
+            voting_period,
+            execution_delay,
+            proposal_deposit,
+        )
+    }
+
+    /// Vote on a governance parameter proposal
+    pub fn vote_on_parameter_proposal(
+        ctx: Context<VoteOnParameterProposal>,
+        proposal_id: u64,
+        vote: bool,
+    ) -> Result<()> {
+        instructions::vote_on_parameter_proposal(ctx, proposal_id, vote)
+    }
+
+    /// Apply a passed parameter proposal to the governance config
+    pub fn execute_parameter_proposal(
+        ctx: Context<ExecuteParameterProposal>,
+        proposal_id: u64,
+    ) -> Result<()> {
+        instructions::execute_parameter_proposal(ctx, proposal_id)
+    }
+
+    /// Propose paying lamports out of the governance treasury
+    pub fn create_treasury_withdrawal_proposal(
+        ctx: Context<CreateTreasuryWithdrawalProposal>,
+        recipient: Pubkey,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::create_treasury_withdrawal_proposal(ctx, recipient, amount)
+    }
+
+    /// Vote on a treasury withdrawal proposal
+    pub fn vote_on_treasury_withdrawal_proposal(
+        ctx: Context<VoteOnTreasuryWithdrawalProposal>,
+        proposal_id: u64,
+        vote: bool,
+    ) -> Result<()> {
+        instructions::vote_on_treasury_withdrawal_proposal(ctx, proposal_id, vote)
+    }
+
+    /// Pay out a passed treasury withdrawal proposal
+    pub fn execute_treasury_withdrawal_proposal(
+        ctx: Context<ExecuteTreasuryWithdrawalProposal>,
+        proposal_id: u64,
+    ) -> Result<()> {
+        instructions::execute_treasury_withdrawal_proposal(ctx, proposal_id)
+    }
+
+    /// Initialize the sequential research proposal ID counter
+    pub fn initialize_proposal_counter(ctx: Context<InitializeProposalCounter>) -> Result<()> {
+        instructions::initialize_proposal_counter(ctx)
+    }
+
     /// Create a research proposal for community voting
     pub fn create_research_proposal(
         ctx: Context<CreateResearchProposal>,
-        proposal_id: u64,
         research_topic: String,
+        scope: state::ResearchScope,
     ) -> Result<()> {
-        instructions::create_research_proposal(ctx, proposal_id, research_topic)
+        instructions::create_research_proposal(ctx, research_topic, scope)
     }
 
     /// Vote on a research proposal
@@ -166,7 +294,50 @@ pub mod hms_solana {
         instructions::vote_on_research_proposal(ctx, proposal_id, vote)
     }
 
-    /// Execute an approved research proposal
+    /// Vote on a research proposal on behalf of a delegator
+    pub fn cast_delegated_research_vote(
+        ctx: Context<CastDelegatedResearchVote>,
+        proposal_id: u64,
+        delegator: Pubkey,
+        vote: bool,
+    ) -> Result<()> {
+        instructions::cast_delegated_research_vote(ctx, proposal_id, delegator, vote)
+    }
+
+    /// Change an existing vote on a research proposal
+    pub fn change_research_vote(
+        ctx: Context<ChangeResearchVote>,
+        proposal_id: u64,
+        voter: Pubkey,
+        vote: bool,
+    ) -> Result<()> {
+        instructions::change_research_vote(ctx, proposal_id, voter, vote)
+    }
+
+    /// Delegate research votes to another account
+    pub fn delegate_research_votes(
+        ctx: Context<DelegateResearchVotes>,
+        delegate: Pubkey,
+    ) -> Result<()> {
+        instructions::delegate_research_votes(ctx, delegate)
+    }
+
+    /// Revoke a research vote delegation
+    pub fn revoke_research_vote_delegation(
+        ctx: Context<RevokeResearchVoteDelegation>,
+    ) -> Result<()> {
+        instructions::revoke_research_vote_delegation(ctx)
+    }
+
+    /// Queue a passed research proposal, starting its execution timelock
+    pub fn queue_research_proposal(
+        ctx: Context<QueueResearchProposal>,
+        proposal_id: u64,
+    ) -> Result<()> {
+        instructions::queue_research_proposal(ctx, proposal_id)
+    }
+
+    /// Execute a queued research proposal once its timelock has elapsed
     pub fn execute_research_proposal(
         ctx: Context<ExecuteResearchProposal>,
         proposal_id: u64,
@@ -174,6 +345,63 @@ pub mod hms_solana {
         instructions::execute_research_proposal(ctx, proposal_id)
     }
 
+    /// Record rejection of an expired research proposal that failed to pass
+    pub fn finalize_research_proposal(
+        ctx: Context<FinalizeResearchProposal>,
+        proposal_id: u64,
+    ) -> Result<()> {
+        instructions::finalize_research_proposal(ctx, proposal_id)
+    }
+
+    /// Withdraw a research proposal before any votes are cast
+    pub fn cancel_research_proposal(
+        ctx: Context<CancelResearchProposal>,
+        proposal_id: u64,
+    ) -> Result<()> {
+        instructions::cancel_research_proposal(ctx, proposal_id)
+    }
+
+    /// Refund or slash the deposit of a finalized research proposal
+    pub fn settle_proposal_deposit(
+        ctx: Context<SettleProposalDeposit>,
+        proposal_id: u64,
+    ) -> Result<()> {
+        instructions::settle_proposal_deposit(ctx, proposal_id)
+    }
+
+    /// Close a finalized research proposal and reclaim its rent
+    pub fn close_research_proposal(
+        ctx: Context<CloseResearchProposal>,
+        proposal_id: u64,
+    ) -> Result<()> {
+        instructions::close_research_proposal(ctx, proposal_id)
+    }
+
+    /// Close a vote on a finalized or closed proposal and reclaim its rent
+    pub fn close_research_vote(
+        ctx: Context<CloseResearchVote>,
+        proposal_id: u64,
+    ) -> Result<()> {
+        instructions::close_research_vote(ctx, proposal_id)
+    }
+
+    /// Consent to a research proposal using your records
+    pub fn grant_research_consent(
+        ctx: Context<GrantResearchConsent>,
+        proposal_id: u64,
+        record_types: Vec<String>,
+    ) -> Result<()> {
+        instructions::grant_research_consent(ctx, proposal_id, record_types)
+    }
+
+    /// Withdraw consent from a research proposal
+    pub fn withdraw_research_consent(
+        ctx: Context<WithdrawResearchConsent>,
+        proposal_id: u64,
+    ) -> Result<()> {
+        instructions::withdraw_research_consent(ctx, proposal_id)
+    }
+
     /// Access record with research grant authorization
     pub fn access_with_research_grant(
         ctx: Context<AccessWithResearchGrant>,
@@ -183,6 +411,88 @@ pub mod hms_solana {
         instructions::access_with_research_grant(ctx, proposal_id, record_id)
     }
 
+    // Research Compensation Instructions
+    /// Escrow SPL tokens to compensate data donors of a research proposal
+    pub fn fund_research_compensation(
+        ctx: Context<FundResearchCompensation>,
+        proposal_id: u64,
+        amount: u64,
+        payout_per_record: u64,
+        payout_per_participant: u64,
+    ) -> Result<()> {
+        instructions::fund_research_compensation(
+            ctx,
+            proposal_id,
+            amount,
+            payout_per_record,
+            payout_per_participant,
+        )
+    }
+
+    /// Claim compensation for research accesses to your records
+    pub fn claim_research_compensation(
+        ctx: Context<ClaimResearchCompensation>,
+        proposal_id: u64,
+    ) -> Result<()> {
+        instructions::claim_research_compensation(ctx, proposal_id)
+    }
+
+    /// Return unclaimed compensation to the researcher
+    pub fn refund_research_compensation(
+        ctx: Context<RefundResearchCompensation>,
+        proposal_id: u64,
+    ) -> Result<()> {
+        instructions::refund_research_compensation(ctx, proposal_id)
+    }
+
+    // Researcher Credential Instructions
+    /// Register a trusted attestor (program upgrade authority only)
+    pub fn register_research_attestor(
+        ctx: Context<RegisterResearchAttestor>,
+        attestor: Pubkey,
+        name: String,
+    ) -> Result<()> {
+        instructions::register_research_attestor(ctx, attestor, name)
+    }
+
+    /// Stop trusting an attestor (program upgrade authority only)
+    pub fn revoke_research_attestor(
+        ctx: Context<RevokeResearchAttestor>,
+        attestor: Pubkey,
+    ) -> Result<()> {
+        instructions::revoke_research_attestor(ctx, attestor)
+    }
+
+    /// Issue an IRB-backed credential to a researcher
+    pub fn issue_researcher_credential(
+        ctx: Context<IssueResearcherCredential>,
+        researcher: Pubkey,
+        institution: String,
+        irb_approval_hash: [u8; 32],
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::issue_researcher_credential(ctx, researcher, institution, irb_approval_hash, expires_at)
+    }
+
+    /// Revoke a researcher credential
+    pub fn revoke_researcher_credential(
+        ctx: Context<RevokeResearcherCredential>,
+        researcher: Pubkey,
+    ) -> Result<()> {
+        instructions::revoke_researcher_credential(ctx, researcher)
+    }
+
+    /// Reissue an expired or revoked researcher credential, or extend an active one
+    pub fn renew_researcher_credential(
+        ctx: Context<RenewResearcherCredential>,
+        researcher: Pubkey,
+        institution: String,
+        irb_approval_hash: [u8; 32],
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::renew_researcher_credential(ctx, researcher, institution, irb_approval_hash, expires_at)
+    }
+
     // Wearable Integration Instructions
     /// Register a wearable device
     pub fn register_wearable_device(
@@ -195,6 +505,7 @@ pub mod hms_solana {
     }
 
     /// Ingest data from wearable device
+    #[allow(clippy::too_many_arguments)]
     pub fn ingest_wearable_data(
         ctx: Context<IngestWearableData>,
         device_id: String,
@@ -237,14 +548,22 @@ pub mod hms_solana {
     }
 
     // Cross-Device Sync Instructions
+    /// Initialize the registry tracking an owner's sync devices
+    pub fn initialize_sync_registry(
+        ctx: Context<InitializeSyncRegistry>,
+        sync_key_commitment: [u8; 32],
+    ) -> Result<()> {
+        instructions::initialize_sync_registry(ctx, sync_key_commitment)
+    }
+
     /// Initialize sync state for a device
     pub fn initialize_sync_state(
         ctx: Context<InitializeSyncState>,
         device_id: String,
-        encrypted_sync_key: [u8; MAX_SYNC_KEY_LENGTH],
+        sync_key_proof: [u8; 32],
         is_primary: bool,
     ) -> Result<()> {
-        instructions::initialize_sync_state(ctx, device_id, encrypted_sync_key, is_primary)
+        instructions::initialize_sync_state(ctx, device_id, sync_key_proof, is_primary)
     }
 
     /// Start a sync operation between devices
@@ -253,9 +572,9 @@ pub mod hms_solana {
         source_device: String,
         target_device: String,
         operation_type: String,
-        sync_key: [u8; MAX_SYNC_KEY_LENGTH],
+        sync_key_proof: [u8; 32],
     ) -> Result<()> {
-        instructions::start_sync_operation(ctx, source_device, target_device, operation_type, sync_key)
+        instructions::start_sync_operation(ctx, source_device, target_device, operation_type, sync_key_proof)
     }
 
     /// Complete a sync operation successfully
@@ -263,10 +582,27 @@ pub mod hms_solana {
         ctx: Context<CompleteSyncOperation>,
         source_device: String,
         target_device: String,
+        operation_id: u64,
         records_synced: u64,
         new_state_hash: [u8; 32],
     ) -> Result<()> {
-        instructions::complete_sync_operation(ctx, source_device, target_device, records_synced, new_state_hash)
+        instructions::complete_sync_operation(
+            ctx,
+            source_device,
+            target_device,
+            operation_id,
+            records_synced,
+            new_state_hash,
+        )
+    }
+
+    /// Record a local change on a device, advancing its version vector
+    pub fn record_sync_update(
+        ctx: Context<RecordSyncUpdate>,
+        device_id: String,
+        new_state_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::record_sync_update(ctx, device_id, new_state_hash)
     }
 
     /// Mark a sync operation as failed
@@ -274,9 +610,39 @@ pub mod hms_solana {
         ctx: Context<FailSyncOperation>,
         source_device: String,
         target_device: String,
+        operation_id: u64,
         error_message: String,
     ) -> Result<()> {
-        instructions::fail_sync_operation(ctx, source_device, target_device, error_message)
+        instructions::fail_sync_operation(ctx, source_device, target_device, operation_id, error_message)
+    }
+
+    /// Fail a sync operation that has been pending past its timeout
+    pub fn timeout_sync_operation(
+        ctx: Context<TimeoutSyncOperation>,
+        owner: Pubkey,
+        source_device: String,
+        target_device: String,
+        operation_id: u64,
+    ) -> Result<()> {
+        instructions::timeout_sync_operation(ctx, owner, source_device, target_device, operation_id)
+    }
+
+    /// Set how long sync operations may stay pending
+    pub fn set_sync_operation_timeout(
+        ctx: Context<SetSyncOperationTimeout>,
+        operation_timeout: i64,
+    ) -> Result<()> {
+        instructions::set_sync_operation_timeout(ctx, operation_timeout)
+    }
+
+    /// Close a finished sync operation to reclaim rent
+    pub fn close_sync_operation(
+        ctx: Context<CloseSyncOperation>,
+        source_device: String,
+        target_device: String,
+        operation_id: u64,
+    ) -> Result<()> {
+        instructions::close_sync_operation(ctx, source_device, target_device, operation_id)
     }
 
     /// Update sync primary device status
@@ -287,4 +653,221 @@ pub mod hms_solana {
     ) -> Result<()> {
         instructions::update_sync_primary(ctx, device_id, is_primary)
     }
+
+    /// Rotate the sync key shared by all of the owner's devices
+    pub fn rotate_sync_key(
+        ctx: Context<RotateSyncKey>,
+        new_sync_key_commitment: [u8; 32],
+    ) -> Result<()> {
+        instructions::rotate_sync_key(ctx, new_sync_key_commitment)
+    }
+
+    /// Revoke a lost or stolen device and force a sync key rotation
+    pub fn revoke_sync_device(
+        ctx: Context<RevokeSyncDevice>,
+        device_id: String,
+    ) -> Result<()> {
+        instructions::revoke_sync_device(ctx, device_id)
+    }
+
+    // Health Certificate Instructions
+    /// Register a clinic or lab allowed to issue health certificates
+    pub fn register_certificate_issuer(
+        ctx: Context<RegisterCertificateIssuer>,
+        issuer: Pubkey,
+        name: String,
+    ) -> Result<()> {
+        instructions::register_certificate_issuer(ctx, issuer, name)
+    }
+
+    /// Revoke a certificate issuer
+    pub fn revoke_certificate_issuer(
+        ctx: Context<RevokeCertificateIssuer>,
+        issuer: Pubkey,
+    ) -> Result<()> {
+        instructions::revoke_certificate_issuer(ctx, issuer)
+    }
+
+    /// Issue a certificate NFT bound to a patient's health record
+    #[allow(clippy::too_many_arguments)]
+    pub fn issue_health_certificate(
+        ctx: Context<IssueHealthCertificate>,
+        record_id: u64,
+        certificate_type: String,
+        expires_at: i64,
+        name: String,
+        symbol: String,
+        uri: String,
+    ) -> Result<()> {
+        instructions::issue_health_certificate(ctx, record_id, certificate_type, expires_at, name, symbol, uri)
+    }
+
+    /// Revoke a health certificate
+    pub fn revoke_health_certificate(
+        ctx: Context<RevokeHealthCertificate>,
+        mint: Pubkey,
+    ) -> Result<()> {
+        instructions::revoke_health_certificate(ctx, mint)
+    }
+
+    /// Check a health certificate and return its status as return data
+    pub fn verify_certificate(
+        ctx: Context<VerifyCertificate>,
+        mint: Pubkey,
+    ) -> Result<state::CertificateStatus> {
+        instructions::verify_certificate(ctx, mint)
+    }
+
+    // HMS Token Instructions
+    /// Create the HMS utility token with a program-controlled mint
+    pub fn initialize_hms_token(
+        ctx: Context<InitializeHmsToken>,
+        decimals: u8,
+        reward_per_contribution: u64,
+    ) -> Result<()> {
+        instructions::initialize_hms_token(ctx, decimals, reward_per_contribution)
+    }
+
+    /// Mint HMS tokens to the owner of a contributed health record
+    pub fn reward_data_contribution(
+        ctx: Context<RewardDataContribution>,
+        record_id: u64,
+    ) -> Result<()> {
+        instructions::reward_data_contribution(ctx, record_id)
+    }
+
+    // Access Ticket Instructions
+    /// Mint a transferable ticket granting access to one of the owner's records
+    pub fn mint_access_ticket(
+        ctx: Context<MintAccessTicket>,
+        record_id: u64,
+        permissions: u8,
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::mint_access_ticket(ctx, record_id, permissions, expires_at)
+    }
+
+    /// Set or clear the address approved to transfer an access ticket
+    pub fn approve_access_ticket(
+        ctx: Context<ApproveAccessTicket>,
+        mint: Pubkey,
+    ) -> Result<()> {
+        instructions::approve_access_ticket(ctx, mint)
+    }
+
+    /// Allow an operator to manage all of the caller's access tickets
+    pub fn approve_ticket_operator(
+        ctx: Context<ApproveTicketOperator>,
+        operator: Pubkey,
+    ) -> Result<()> {
+        instructions::approve_ticket_operator(ctx, operator)
+    }
+
+    /// Withdraw an operator's approval over the caller's access tickets
+    pub fn revoke_ticket_operator(
+        ctx: Context<RevokeTicketOperator>,
+        operator: Pubkey,
+    ) -> Result<()> {
+        instructions::revoke_ticket_operator(ctx, operator)
+    }
+
+    /// Transfer an access ticket as its holder, approved address or operator
+    pub fn transfer_access_ticket(
+        ctx: Context<TransferAccessTicket>,
+        mint: Pubkey,
+    ) -> Result<()> {
+        instructions::transfer_access_ticket(ctx, mint)
+    }
+
+    /// Revoke an access ticket, ending the access it delegates
+    pub fn revoke_access_ticket(
+        ctx: Context<RevokeAccessTicket>,
+        mint: Pubkey,
+    ) -> Result<()> {
+        instructions::revoke_access_ticket(ctx, mint)
+    }
+
+    // Access Pricing Instructions
+    /// Set or clear the price a grantee pays for access under a grant
+    pub fn set_access_price(
+        ctx: Context<SetAccessPrice>,
+        grantee: Pubkey,
+        price: Option<state::AccessPrice>,
+    ) -> Result<()> {
+        instructions::set_access_price(ctx, grantee, price)
+    }
+
+    /// Pay for the next period of a grant priced per period
+    pub fn pay_access_period(
+        ctx: Context<PayAccessPeriod>,
+        max_price: u64,
+        expected_mint: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::pay_access_period(ctx, max_price, expected_mint)
+    }
+
+    /// Grow an access grant created before pricing to the current layout
+    pub fn migrate_access_grant(
+        ctx: Context<MigrateAccessGrant>,
+        grantee: Pubkey,
+    ) -> Result<()> {
+        instructions::migrate_access_grant(ctx, grantee)
+    }
+
+    // Contribution Reward Instructions
+    /// Create the HMS contribution reward schedule (program upgrade authority only)
+    pub fn initialize_reward_schedule(
+        ctx: Context<InitializeRewardSchedule>,
+        params: state::RewardScheduleParams,
+    ) -> Result<()> {
+        instructions::initialize_reward_schedule(ctx, params)
+    }
+
+    /// Propose a new contribution reward schedule
+    pub fn create_reward_schedule_proposal(
+        ctx: Context<CreateRewardScheduleProposal>,
+        params: state::RewardScheduleParams,
+    ) -> Result<()> {
+        instructions::create_reward_schedule_proposal(ctx, params)
+    }
+
+    /// Vote on a reward schedule proposal
+    pub fn vote_on_reward_schedule_proposal(
+        ctx: Context<VoteOnRewardScheduleProposal>,
+        proposal_id: u64,
+        vote: bool,
+    ) -> Result<()> {
+        instructions::vote_on_reward_schedule_proposal(ctx, proposal_id, vote)
+    }
+
+    /// Apply a passed reward schedule proposal once voting has closed
+    pub fn execute_reward_schedule_proposal(
+        ctx: Context<ExecuteRewardScheduleProposal>,
+        proposal_id: u64,
+    ) -> Result<()> {
+        instructions::execute_reward_schedule_proposal(ctx, proposal_id)
+    }
+
+    /// Create the account tracking a contributor's per-epoch rewards
+    pub fn initialize_contributor_rewards(ctx: Context<InitializeContributorRewards>) -> Result<()> {
+        instructions::initialize_contributor_rewards(ctx)
+    }
+
+    /// Claim the HMS reward for an ingested wearable data batch
+    pub fn claim_wearable_batch_reward(
+        ctx: Context<ClaimWearableBatchReward>,
+        device_id: String,
+        batch_id: u64,
+    ) -> Result<()> {
+        instructions::claim_wearable_batch_reward(ctx, device_id, batch_id)
+    }
+
+    /// Claim the HMS reward for a record attested by a valid health certificate
+    pub fn claim_attested_record_reward(
+        ctx: Context<ClaimAttestedRecordReward>,
+        certificate_mint: Pubkey,
+        record_id: u64,
+    ) -> Result<()> {
+        instructions::claim_attested_record_reward(ctx, certificate_mint, record_id)
+    }
 }
@@ -10,6 +10,10 @@ pub struct AccessGrant {
     pub created_at: i64,
     pub last_updated: i64,
     pub bump: u8,
+    /// Price the grantee pays the owner for access (None = free)
+    pub price: Option<AccessPrice>,
+    /// End of the period already paid for, used when `price.period` is set
+    pub paid_until: i64,
 }
 
 impl AccessGrant {
@@ -20,7 +24,9 @@ impl AccessGrant {
         1 +  // permissions
         8 +  // created_at
         8 +  // last_updated
-        1;   // bump
+        1 +  // bump
+        1 + AccessPrice::LEN + // price
+        8;   // paid_until
 
     pub fn new(
         owner: Pubkey,
@@ -52,6 +58,8 @@ impl AccessGrant {
             created_at: now,
             last_updated: now,
             bump,
+            price: None,
+            paid_until: 0,
         })
     }
 
@@ -64,6 +72,42 @@ impl AccessGrant {
         self.is_valid() && (self.permissions & permission) != 0
     }
 
+    pub fn set_price(&mut self, price: Option<AccessPrice>) -> Result<()> {
+        if let Some(price) = price.as_ref() {
+            price.validate()?;
+        }
+
+        self.price = price;
+        self.last_updated = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+
+    /// Whether a per-period grant has been paid for at the current time
+    pub fn is_paid_up(&self) -> bool {
+        let now = Clock::get().unwrap().unix_timestamp;
+        now < self.paid_until
+    }
+
+    /// Extend the paid period, starting from now if the previous one lapsed.
+    /// A period running past the grant's expiry is cut short and charged pro
+    /// rata. Returns the amount due and the new end of the paid period.
+    pub fn record_period_payment(&mut self, price: &AccessPrice) -> Result<(u64, i64)> {
+        let now = Clock::get()?.unix_timestamp;
+        let start = self.paid_until.max(now);
+
+        require!(
+            start < self.expires_at,
+            crate::errors::HealthManagerError::AccessPaidToGrantExpiry
+        );
+
+        let covered = price.period.min(self.expires_at - start);
+        let amount = (price.amount as u128 * covered as u128 / price.period as u128) as u64;
+
+        self.paid_until = start + covered;
+        self.last_updated = now;
+        Ok((amount, self.paid_until))
+    }
+
     pub fn update_permissions(&mut self, permissions: u8) -> Result<()> {
         require!(
             permissions > 0 && permissions <= (PERMISSION_READ | PERMISSION_WRITE | PERMISSION_SHARE),
@@ -91,4 +135,118 @@ impl AccessGrant {
         self.last_updated = now;
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+/// What a grantee pays the owner for access under an `AccessGrant`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AccessPrice {
+    /// Amount paid, in lamports or base units of `mint`
+    pub amount: u64,
+    /// SPL token to pay in (None = SOL)
+    pub mint: Option<Pubkey>,
+    /// Length of each paid period in seconds (0 = pay on every read)
+    pub period: i64,
+}
+
+impl AccessPrice {
+    pub const LEN: usize =
+        8 +  // amount
+        1 + 32 + // mint
+        8;   // period
+
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.amount > 0,
+            crate::errors::HealthManagerError::InvalidTokenAmount
+        );
+        require!(
+            self.period >= 0 && self.period <= MAX_ACCESS_DURATION,
+            crate::errors::HealthManagerError::InvalidAccessDuration
+        );
+        Ok(())
+    }
+
+    pub fn is_per_read(&self) -> bool {
+        self.period == 0
+    }
+
+    /// Guard against the owner raising the price or switching tokens between
+    /// the payer signing and the transaction landing
+    pub fn check_limit(&self, max_price: u64, expected_mint: Option<Pubkey>) -> Result<()> {
+        require!(
+            self.amount <= max_price,
+            crate::errors::HealthManagerError::AccessPriceAboveMax
+        );
+        require!(
+            self.mint == expected_mint,
+            crate::errors::HealthManagerError::AccessPriceMintMismatch
+        );
+        Ok(())
+    }
+}
+
+#[account]
+pub struct CollectionAccessGrant {
+    /// Owner of the records being shared
+    pub owner: Pubkey,
+    /// Verified Metaplex collection whose holders are granted access
+    pub collection: Pubkey,
+    pub expires_at: i64,
+    pub permissions: u8,
+    pub created_at: i64,
+    pub last_updated: i64,
+    pub bump: u8,
+}
+
+impl CollectionAccessGrant {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // owner
+        32 + // collection
+        8 +  // expires_at
+        1 +  // permissions
+        8 +  // created_at
+        8 +  // last_updated
+        1;   // bump
+
+    pub fn new(
+        owner: Pubkey,
+        collection: Pubkey,
+        expires_at: i64,
+        permissions: u8,
+        bump: u8,
+    ) -> Result<Self> {
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(
+            expires_at > now,
+            crate::errors::HealthManagerError::InvalidTimestamp
+        );
+        require!(
+            expires_at <= now + MAX_ACCESS_DURATION,
+            crate::errors::HealthManagerError::InvalidAccessDuration
+        );
+        require!(
+            permissions > 0 && permissions <= (PERMISSION_READ | PERMISSION_WRITE),
+            crate::errors::HealthManagerError::InvalidPermissions
+        );
+
+        Ok(Self {
+            owner,
+            collection,
+            expires_at,
+            permissions,
+            created_at: now,
+            last_updated: now,
+            bump,
+        })
+    }
+
+    pub fn is_valid(&self) -> bool {
+        let now = Clock::get().unwrap().unix_timestamp;
+        now < self.expires_at
+    }
+
+    pub fn has_permission(&self, permission: u8) -> bool {
+        self.is_valid() && (self.permissions & permission) != 0
+    }
+}
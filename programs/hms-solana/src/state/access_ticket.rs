@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+
+#[account]
+pub struct AccessTicket {
+    /// Mint of the ticket token (supply fixed at 1); whoever owns the token
+    /// account holding it is the ticket holder
+    pub mint: Pubkey,
+    /// Owner of the record the ticket grants access to
+    pub record_owner: Pubkey,
+    /// Record the ticket grants access to
+    pub record_id: u64,
+    /// Permissions granted to the holder
+    pub permissions: u8,
+    /// Timestamp after which the ticket no longer grants access
+    pub expires_at: i64,
+    /// Timestamp when the ticket was minted
+    pub created_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl AccessTicket {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // mint
+        32 + // record_owner
+        8 +  // record_id
+        1 +  // permissions
+        8 +  // expires_at
+        8 +  // created_at
+        1;   // bump
+
+    pub fn new(
+        mint: Pubkey,
+        record_owner: Pubkey,
+        record_id: u64,
+        permissions: u8,
+        expires_at: i64,
+        bump: u8,
+    ) -> Result<Self> {
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(
+            expires_at > now,
+            crate::errors::HealthManagerError::InvalidTimestamp
+        );
+        require!(
+            expires_at <= now + MAX_ACCESS_DURATION,
+            crate::errors::HealthManagerError::InvalidAccessDuration
+        );
+        require!(
+            permissions > 0 && permissions <= (PERMISSION_READ | PERMISSION_WRITE),
+            crate::errors::HealthManagerError::InvalidPermissions
+        );
+
+        Ok(Self {
+            mint,
+            record_owner,
+            record_id,
+            permissions,
+            expires_at,
+            created_at: now,
+            bump,
+        })
+    }
+
+    /// Same rule as ERC-721: the holder, the approved address or one of the
+    /// holder's operators may move the ticket. `holder` and `approved` are the
+    /// owner and delegate of the token account holding the ticket.
+    pub fn is_approved_or_owner(
+        holder: Pubkey,
+        approved: Option<Pubkey>,
+        spender: Pubkey,
+        is_operator: bool,
+    ) -> bool {
+        spender == holder || approved == Some(spender) || is_operator
+    }
+
+    pub fn is_valid(&self) -> bool {
+        let now = Clock::get().unwrap().unix_timestamp;
+        now < self.expires_at
+    }
+
+    pub fn has_permission(&self, permission: u8) -> bool {
+        self.is_valid() && (self.permissions & permission) != 0
+    }
+}
+
+#[account]
+pub struct TicketOperatorApproval {
+    /// Ticket holder who approved the operator
+    pub holder: Pubkey,
+    /// Address allowed to manage all of the holder's tickets
+    pub operator: Pubkey,
+    /// Timestamp when the approval was given
+    pub approved_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl TicketOperatorApproval {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // holder
+        32 + // operator
+        8 +  // approved_at
+        1;   // bump
+
+    pub fn new(
+        holder: Pubkey,
+        operator: Pubkey,
+        bump: u8,
+    ) -> Result<Self> {
+        Ok(Self {
+            holder,
+            operator,
+            approved_at: Clock::get()?.unix_timestamp,
+            bump,
+        })
+    }
+}
@@ -0,0 +1,220 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::state::{GovernanceConfig, ProposalTally};
+
+/// Reward amounts and anti-farming limits, set by governance
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RewardScheduleParams {
+    /// HMS paid for each newly ingested wearable data batch
+    pub wearable_batch_reward: u64,
+    /// HMS paid for each record attested by a valid health certificate
+    pub attested_record_reward: u64,
+    /// Length of a reward epoch in seconds
+    pub epoch_duration: i64,
+    /// Most HMS a single contributor can earn in one epoch
+    pub contributor_epoch_cap: u64,
+    /// Most HMS paid out to all contributors in one epoch
+    pub epoch_budget: u64,
+}
+
+impl RewardScheduleParams {
+    pub const LEN: usize =
+        8 +  // wearable_batch_reward
+        8 +  // attested_record_reward
+        8 +  // epoch_duration
+        8 +  // contributor_epoch_cap
+        8;   // epoch_budget
+
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.epoch_duration > 0 && self.epoch_duration <= MAX_REWARD_EPOCH_DURATION,
+            crate::errors::HealthManagerError::InvalidRewardSchedule
+        );
+        require!(
+            self.contributor_epoch_cap <= self.epoch_budget,
+            crate::errors::HealthManagerError::InvalidRewardSchedule
+        );
+        Ok(())
+    }
+}
+
+#[account]
+pub struct RewardSchedule {
+    /// Current reward amounts and limits
+    pub params: RewardScheduleParams,
+    /// Epoch `epoch_distributed` refers to, counted from the schedule's creation
+    pub current_epoch: u64,
+    /// Timestamp when `current_epoch` started
+    pub epoch_started_at: i64,
+    /// HMS paid out to all contributors in `current_epoch`
+    pub epoch_distributed: u64,
+    /// Timestamp of the last change to `params`
+    pub updated_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl RewardSchedule {
+    pub const LEN: usize = 8 + // discriminator
+        RewardScheduleParams::LEN + // params
+        8 +  // current_epoch
+        8 +  // epoch_started_at
+        8 +  // epoch_distributed
+        8 +  // updated_at
+        1;   // bump
+
+    pub fn new(params: RewardScheduleParams, bump: u8) -> Result<Self> {
+        params.validate()?;
+
+        let now = Clock::get()?.unix_timestamp;
+        Ok(Self {
+            params,
+            current_epoch: 0,
+            epoch_started_at: now,
+            epoch_distributed: 0,
+            updated_at: now,
+            bump,
+        })
+    }
+
+    pub fn apply(&mut self, params: RewardScheduleParams) -> Result<()> {
+        params.validate()?;
+
+        self.params = params;
+        self.updated_at = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+
+    /// Pay up to `amount` to a contributor, limited by both per-epoch caps.
+    /// Returns the amount actually paid and the epoch it counts towards.
+    pub fn allocate(
+        &mut self,
+        contributor: &mut ContributorRewards,
+        amount: u64,
+    ) -> Result<(u64, u64)> {
+        require!(
+            amount > 0,
+            crate::errors::HealthManagerError::ContributionNotRewarded
+        );
+
+        // Epochs are counted from the start of the current one, so a new
+        // `epoch_duration` only moves the end of that epoch instead of
+        // renumbering every epoch
+        let elapsed = Clock::get()?.unix_timestamp - self.epoch_started_at;
+        let epochs_passed = elapsed / self.params.epoch_duration;
+        if epochs_passed > 0 {
+            self.current_epoch += epochs_passed as u64;
+            self.epoch_started_at += epochs_passed * self.params.epoch_duration;
+            self.epoch_distributed = 0;
+        }
+        let epoch = self.current_epoch;
+        if contributor.epoch != epoch {
+            contributor.epoch = epoch;
+            contributor.epoch_rewarded = 0;
+        }
+
+        let payable = amount
+            .min(self.params.contributor_epoch_cap.saturating_sub(contributor.epoch_rewarded))
+            .min(self.params.epoch_budget.saturating_sub(self.epoch_distributed));
+        require!(
+            payable > 0,
+            crate::errors::HealthManagerError::RewardEpochCapReached
+        );
+
+        self.epoch_distributed += payable;
+        contributor.epoch_rewarded += payable;
+        contributor.total_rewarded += payable;
+
+        Ok((payable, epoch))
+    }
+}
+
+#[account]
+pub struct ContributorRewards {
+    /// Contributor whose rewards are tracked
+    pub contributor: Pubkey,
+    /// Epoch `epoch_rewarded` refers to
+    pub epoch: u64,
+    /// HMS earned in `epoch`
+    pub epoch_rewarded: u64,
+    /// HMS earned across all epochs
+    pub total_rewarded: u64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl ContributorRewards {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // contributor
+        8 +  // epoch
+        8 +  // epoch_rewarded
+        8 +  // total_rewarded
+        1;   // bump
+
+    pub fn new(contributor: Pubkey, bump: u8) -> Result<Self> {
+        Ok(Self {
+            contributor,
+            epoch: 0,
+            epoch_rewarded: 0,
+            total_rewarded: 0,
+            bump,
+        })
+    }
+}
+
+#[account]
+pub struct RewardScheduleProposal {
+    /// Unique proposal ID
+    pub proposal_id: u64,
+    /// Profile holder proposing the change
+    pub proposer: Pubkey,
+    /// Proposed reward schedule
+    pub params: RewardScheduleParams,
+    /// Votes and execution state
+    pub tally: ProposalTally,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl RewardScheduleProposal {
+    pub const LEN: usize = 8 + // discriminator
+        8 +  // proposal_id
+        32 + // proposer
+        RewardScheduleParams::LEN + // params
+        ProposalTally::LEN + // tally
+        1;   // bump
+
+    pub fn new(
+        proposal_id: u64,
+        proposer: Pubkey,
+        params: RewardScheduleParams,
+        config: &GovernanceConfig,
+        bump: u8,
+    ) -> Result<Self> {
+        params.validate()?;
+
+        Ok(Self {
+            proposal_id,
+            proposer,
+            params,
+            tally: ProposalTally::new(config)?,
+            bump,
+        })
+    }
+
+    pub fn cast_vote(&mut self, vote: bool) -> Result<()> {
+        require!(
+            !self.tally.is_expired(),
+            crate::errors::HealthManagerError::RewardProposalExpired
+        );
+
+        self.tally.record_vote(vote);
+        Ok(())
+    }
+
+    pub fn execute(&mut self, schedule: &mut RewardSchedule) -> Result<()> {
+        self.tally.execute()?;
+
+        schedule.apply(self.params.clone())
+    }
+}
@@ -1,6 +1,336 @@
 use anchor_lang::prelude::*;
 use crate::constants::*;
 
+#[account]
+pub struct GovernanceConfig {
+    /// Minimum number of votes cast for a proposal to be valid
+    pub quorum_votes: u64,
+    /// Share of yes votes (in basis points) a proposal must exceed to pass
+    pub approval_threshold_bps: u16,
+    /// Voting period for new proposals in seconds
+    pub voting_period: i64,
+    /// Timelock between queueing an approved proposal and its execution (seconds)
+    pub execution_delay: i64,
+    /// Deposit required to create a research proposal (lamports; SOL only,
+    /// SPL token deposits are not supported)
+    pub proposal_deposit: u64,
+    /// Timestamp when config was last updated
+    pub last_updated: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl GovernanceConfig {
+    pub const LEN: usize = 8 + // discriminator
+        8 +  // quorum_votes
+        2 +  // approval_threshold_bps
+        8 +  // voting_period
+        8 +  // execution_delay
+        8 +  // proposal_deposit
+        8 +  // last_updated
+        1;   // bump
+
+    pub fn new(
+        quorum_votes: u64,
+        approval_threshold_bps: u16,
+        voting_period: i64,
+        execution_delay: i64,
+        proposal_deposit: u64,
+        bump: u8,
+    ) -> Result<Self> {
+        let mut config = Self {
+            quorum_votes: 0,
+            approval_threshold_bps: 0,
+            voting_period: 0,
+            execution_delay: 0,
+            proposal_deposit: 0,
+            last_updated: 0,
+            bump,
+        };
+        config.apply(
+            quorum_votes,
+            approval_threshold_bps,
+            voting_period,
+            execution_delay,
+            proposal_deposit,
+        )?;
+        Ok(config)
+    }
+
+    pub fn validate_params(
+        quorum_votes: u64,
+        approval_threshold_bps: u16,
+        voting_period: i64,
+        execution_delay: i64,
+    ) -> Result<()> {
+        require!(
+            quorum_votes > 0 &&
+            (MIN_APPROVAL_THRESHOLD_BPS..BASIS_POINTS_DENOMINATOR).contains(&approval_threshold_bps) &&
+            voting_period > 0 &&
+            voting_period <= MAX_VOTING_PERIOD &&
+            (0..=MAX_EXECUTION_DELAY).contains(&execution_delay),
+            crate::errors::HealthManagerError::InvalidGovernanceParameters
+        );
+        Ok(())
+    }
+
+    pub fn apply(
+        &mut self,
+        quorum_votes: u64,
+        approval_threshold_bps: u16,
+        voting_period: i64,
+        execution_delay: i64,
+        proposal_deposit: u64,
+    ) -> Result<()> {
+        Self::validate_params(quorum_votes, approval_threshold_bps, voting_period, execution_delay)?;
+
+        self.quorum_votes = quorum_votes;
+        self.approval_threshold_bps = approval_threshold_bps;
+        self.voting_period = voting_period;
+        self.execution_delay = execution_delay;
+        self.proposal_deposit = proposal_deposit;
+        self.last_updated = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+
+    /// Whether a tally reaches quorum and its yes share exceeds the threshold
+    pub fn vote_passes(
+        yes_votes: u64,
+        no_votes: u64,
+        quorum_votes: u64,
+        approval_threshold_bps: u16,
+    ) -> bool {
+        let total = yes_votes as u128 + no_votes as u128;
+        total >= quorum_votes as u128 &&
+        yes_votes as u128 * BASIS_POINTS_DENOMINATOR as u128 > total * approval_threshold_bps as u128
+    }
+}
+
+/// Vote tally and lifecycle shared by parameter, reward schedule and treasury proposals
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ProposalTally {
+    /// Number of yes votes
+    pub yes_votes: u64,
+    /// Number of no votes
+    pub no_votes: u64,
+    /// Quorum in effect when the proposal was created
+    pub required_quorum: u64,
+    /// Approval threshold in effect when the proposal was created
+    pub required_threshold_bps: u16,
+    /// Timestamp when proposal was created
+    pub created_at: i64,
+    /// Timestamp when voting ends
+    pub expires_at: i64,
+    /// Whether the proposal has been executed
+    pub is_executed: bool,
+}
+
+impl ProposalTally {
+    pub const LEN: usize =
+        8 +  // yes_votes
+        8 +  // no_votes
+        8 +  // required_quorum
+        2 +  // required_threshold_bps
+        8 +  // created_at
+        8 +  // expires_at
+        1;   // is_executed
+
+    pub fn new(config: &GovernanceConfig) -> Result<Self> {
+        let now = Clock::get()?.unix_timestamp;
+
+        Ok(Self {
+            yes_votes: 0,
+            no_votes: 0,
+            required_quorum: config.quorum_votes,
+            required_threshold_bps: config.approval_threshold_bps,
+            created_at: now,
+            expires_at: now + config.voting_period,
+            is_executed: false,
+        })
+    }
+
+    pub fn is_expired(&self) -> bool {
+        let now = Clock::get().unwrap().unix_timestamp;
+        now >= self.expires_at
+    }
+
+    /// Count a vote; callers reject it first if voting has closed
+    pub fn record_vote(&mut self, vote: bool) {
+        if vote {
+            self.yes_votes += 1;
+        } else {
+            self.no_votes += 1;
+        }
+    }
+
+    /// Mark the proposal executed. Changes only take effect once voting has
+    /// closed and the vote passed, and only once.
+    pub fn execute(&mut self) -> Result<()> {
+        require!(
+            self.is_expired(),
+            crate::errors::HealthManagerError::VotingPeriodActive
+        );
+        require!(
+            !self.is_executed,
+            crate::errors::HealthManagerError::ProposalAlreadyExecuted
+        );
+        require!(
+            GovernanceConfig::vote_passes(
+                self.yes_votes,
+                self.no_votes,
+                self.required_quorum,
+                self.required_threshold_bps,
+            ),
+            crate::errors::HealthManagerError::ProposalNotPassed
+        );
+
+        self.is_executed = true;
+        Ok(())
+    }
+}
+
+#[account]
+pub struct GovernanceTreasury {
+    /// Total lamports received from slashed proposal deposits
+    pub total_slashed: u64,
+    /// Total lamports paid out through approved withdrawal proposals
+    pub total_withdrawn: u64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl GovernanceTreasury {
+    pub const LEN: usize = 8 + // discriminator
+        8 +  // total_slashed
+        8 +  // total_withdrawn
+        1;   // bump
+
+    pub fn new(bump: u8) -> Self {
+        Self {
+            total_slashed: 0,
+            total_withdrawn: 0,
+            bump,
+        }
+    }
+}
+
+#[account]
+pub struct ProposalDeposit {
+    /// Proposal the deposit was paid for
+    pub proposal_id: u64,
+    /// Researcher who paid the deposit
+    pub depositor: Pubkey,
+    /// Deposit held on top of the account's rent (lamports)
+    pub amount: u64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl ProposalDeposit {
+    pub const LEN: usize = 8 + // discriminator
+        8 +  // proposal_id
+        32 + // depositor
+        8 +  // amount
+        1;   // bump
+
+    pub fn new(proposal_id: u64, depositor: Pubkey, amount: u64, bump: u8) -> Self {
+        Self {
+            proposal_id,
+            depositor,
+            amount,
+            bump,
+        }
+    }
+}
+
+#[account]
+pub struct ProposalCounter {
+    /// ID that will be assigned to the next proposal of the counter's kind
+    pub next_proposal_id: u64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl ProposalCounter {
+    pub const LEN: usize = 8 + // discriminator
+        8 +  // next_proposal_id
+        1;   // bump
+
+    pub fn new(bump: u8) -> Self {
+        Self {
+            next_proposal_id: 0,
+            bump,
+        }
+    }
+
+    /// Hand out the current ID and advance the counter
+    pub fn assign_next(&mut self) -> u64 {
+        let proposal_id = self.next_proposal_id;
+        self.next_proposal_id += 1;
+        proposal_id
+    }
+}
+
+/// What an approved research proposal is allowed to read, and until when
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct ResearchScope {
+    /// Record types the research may read (empty = all record types)
+    pub record_types: Vec<String>,
+    /// Description of the eligible cohort, shown to data owners before consenting
+    pub cohort_criteria: String,
+    /// Earliest record timestamp covered (0 = unbounded)
+    pub record_start: i64,
+    /// Latest record timestamp covered (0 = unbounded)
+    pub record_end: i64,
+    /// Timestamp after which research access ends
+    pub access_expires_at: i64,
+}
+
+impl ResearchScope {
+    pub const LEN: usize =
+        4 + MAX_RESEARCH_RECORD_TYPES * (4 + MAX_RECORD_TYPE_LENGTH) + // record_types
+        4 + MAX_COHORT_CRITERIA_LENGTH + // cohort_criteria
+        8 +  // record_start
+        8 +  // record_end
+        8;   // access_expires_at
+
+    pub fn validate(&self, voting_ends_at: i64) -> Result<()> {
+        require!(
+            self.record_types.len() <= MAX_RESEARCH_RECORD_TYPES,
+            crate::errors::HealthManagerError::TooManyRecordTypes
+        );
+        require!(
+            self.record_types.iter().all(|t| t.len() <= MAX_RECORD_TYPE_LENGTH),
+            crate::errors::HealthManagerError::RecordTypeTooLong
+        );
+        require!(
+            self.cohort_criteria.len() <= MAX_COHORT_CRITERIA_LENGTH,
+            crate::errors::HealthManagerError::MetadataTooLong
+        );
+        require!(
+            self.record_end == 0 || self.record_start <= self.record_end,
+            crate::errors::HealthManagerError::InvalidResearchScope
+        );
+        require!(
+            self.access_expires_at > voting_ends_at &&
+            self.access_expires_at <= voting_ends_at + MAX_ACCESS_DURATION,
+            crate::errors::HealthManagerError::InvalidAccessDuration
+        );
+        Ok(())
+    }
+
+    pub fn covers_record(&self, record_type: &str, record_timestamp: i64) -> bool {
+        (self.record_types.is_empty() || self.record_types.iter().any(|t| t == record_type)) &&
+        (self.record_start == 0 || record_timestamp >= self.record_start) &&
+        (self.record_end == 0 || record_timestamp <= self.record_end)
+    }
+
+    pub fn is_access_active(&self) -> bool {
+        let now = Clock::get().unwrap().unix_timestamp;
+        now < self.access_expires_at
+    }
+}
+
 #[account]
 pub struct ResearchProposal {
     /// Unique proposal ID
@@ -9,6 +339,8 @@ pub struct ResearchProposal {
     pub researcher: Pubkey,
     /// Research topic/description
     pub research_topic: String,
+    /// Records and time window the research is limited to
+    pub scope: ResearchScope,
     /// Number of yes votes
     pub yes_votes: u64,
     /// Number of no votes
@@ -19,10 +351,24 @@ pub struct ResearchProposal {
     pub created_at: i64,
     /// Timestamp when proposal expires
     pub expires_at: i64,
+    /// Quorum in effect when the proposal was created
+    pub quorum_votes: u64,
+    /// Approval threshold (basis points) in effect when the proposal was created
+    pub approval_threshold_bps: u16,
+    /// Execution timelock in effect when the proposal was created
+    pub execution_delay: i64,
+    /// Timestamp when the proposal was queued for execution (0 = not queued)
+    pub queued_at: i64,
     /// Whether the proposal has been executed
     pub is_executed: bool,
     /// Whether the proposal passed
     pub is_approved: bool,
+    /// Whether the proposal was finalized as rejected
+    pub is_rejected: bool,
+    /// Whether the researcher withdrew the proposal
+    pub is_cancelled: bool,
+    /// Whether the proposal deposit has been refunded or slashed
+    pub deposit_settled: bool,
     /// PDA bump
     pub bump: u8,
 }
@@ -32,19 +378,29 @@ impl ResearchProposal {
         8 +  // proposal_id
         32 + // researcher
         4 + MAX_METADATA_LENGTH + // research_topic
+        ResearchScope::LEN + // scope
         8 +  // yes_votes
         8 +  // no_votes
         8 +  // total_voters
         8 +  // created_at
         8 +  // expires_at
+        8 +  // quorum_votes
+        2 +  // approval_threshold_bps
+        8 +  // execution_delay
+        8 +  // queued_at
         1 +  // is_executed
         1 +  // is_approved
+        1 +  // is_rejected
+        1 +  // is_cancelled
+        1 +  // deposit_settled
         1;   // bump
 
     pub fn new(
         proposal_id: u64,
         researcher: Pubkey,
         research_topic: String,
+        scope: ResearchScope,
+        config: &GovernanceConfig,
         bump: u8,
     ) -> Result<Self> {
         require!(
@@ -52,24 +408,38 @@ impl ResearchProposal {
             crate::errors::HealthManagerError::MetadataTooLong
         );
 
+        // Research access cannot start before the timelock has run out
         let now = Clock::get()?.unix_timestamp;
+        scope.validate(now + config.voting_period + config.execution_delay)?;
 
         Ok(Self {
             proposal_id,
             researcher,
             research_topic,
+            scope,
             yes_votes: 0,
             no_votes: 0,
             total_voters: 0,
             created_at: now,
-            expires_at: now + RESEARCH_PROPOSAL_DURATION,
+            expires_at: now + config.voting_period,
+            quorum_votes: config.quorum_votes,
+            approval_threshold_bps: config.approval_threshold_bps,
+            execution_delay: config.execution_delay,
+            queued_at: 0,
             is_executed: false,
             is_approved: false,
+            is_rejected: false,
+            is_cancelled: false,
+            deposit_settled: false,
             bump,
         })
     }
 
     pub fn cast_vote(&mut self, vote: bool) -> Result<()> {
+        require!(
+            !self.is_finalized(),
+            crate::errors::HealthManagerError::ResearchProposalFinalized
+        );
         require!(
             !self.is_expired(),
             crate::errors::HealthManagerError::ResearchProposalExpired
@@ -85,21 +455,137 @@ impl ResearchProposal {
         Ok(())
     }
 
+    /// Move an existing vote between the yes and no tallies
+    pub fn change_vote(&mut self, previous_vote: bool, vote: bool) -> Result<()> {
+        require!(
+            !self.is_finalized(),
+            crate::errors::HealthManagerError::ResearchProposalFinalized
+        );
+        require!(
+            !self.is_expired(),
+            crate::errors::HealthManagerError::ResearchProposalExpired
+        );
+
+        if previous_vote != vote {
+            if vote {
+                self.no_votes = self.no_votes
+                    .checked_sub(1)
+                    .ok_or(crate::errors::HealthManagerError::VoteTallyUnderflow)?;
+                self.yes_votes += 1;
+            } else {
+                self.yes_votes = self.yes_votes
+                    .checked_sub(1)
+                    .ok_or(crate::errors::HealthManagerError::VoteTallyUnderflow)?;
+                self.no_votes += 1;
+            }
+        }
+
+        Ok(())
+    }
+
     pub fn is_expired(&self) -> bool {
         let now = Clock::get().unwrap().unix_timestamp;
         now >= self.expires_at
     }
 
-    pub fn can_execute(&self) -> bool {
-        !self.is_executed &&
-        !self.is_expired() &&
-        self.yes_votes >= MIN_RESEARCH_VOTES &&
-        self.yes_votes > self.no_votes
+    /// Whether the proposal has reached a terminal state
+    pub fn is_finalized(&self) -> bool {
+        self.is_executed || self.is_rejected || self.is_cancelled
+    }
+
+    /// Approved proposals stay open until their research access period ends
+    pub fn can_close(&self) -> bool {
+        self.is_finalized() && (!self.is_approved || !self.scope.is_access_active())
+    }
+
+    /// A quorate rejection by a supermajority marks the proposal as spam
+    pub fn is_slashable(&self) -> bool {
+        let total = self.yes_votes as u128 + self.no_votes as u128;
+        self.is_rejected &&
+        total >= self.quorum_votes as u128 &&
+        self.no_votes as u128 * BASIS_POINTS_DENOMINATOR as u128 >=
+            total * DEPOSIT_SLASH_THRESHOLD_BPS as u128
+    }
+
+    pub fn settle_deposit(&mut self) -> Result<()> {
+        require!(
+            self.is_finalized(),
+            crate::errors::HealthManagerError::ResearchProposalNotFinalized
+        );
+        require!(
+            !self.deposit_settled,
+            crate::errors::HealthManagerError::DepositAlreadySettled
+        );
+
+        self.deposit_settled = true;
+        Ok(())
+    }
+
+    pub fn has_passed(&self) -> bool {
+        GovernanceConfig::vote_passes(
+            self.yes_votes,
+            self.no_votes,
+            self.quorum_votes,
+            self.approval_threshold_bps,
+        )
+    }
+
+    /// Yes votes alone reach quorum with a supermajority, so the proposal
+    /// may be queued before voting closes
+    pub fn has_supermajority(&self) -> bool {
+        let total = self.yes_votes as u128 + self.no_votes as u128;
+        self.yes_votes >= self.quorum_votes &&
+        self.yes_votes as u128 * BASIS_POINTS_DENOMINATOR as u128 >=
+            total * EARLY_QUEUE_THRESHOLD_BPS as u128
+    }
+
+    pub fn is_queued(&self) -> bool {
+        self.queued_at > 0
+    }
+
+    pub fn executable_at(&self) -> i64 {
+        self.queued_at + self.execution_delay
+    }
+
+    pub fn can_queue(&self) -> bool {
+        !self.is_finalized() &&
+        !self.is_queued() &&
+        if self.is_expired() { self.has_passed() } else { self.has_supermajority() }
+    }
+
+    /// Start the timelock for a proposal that passed (or holds a supermajority)
+    pub fn queue(&mut self) -> Result<()> {
+        require!(
+            !self.is_queued(),
+            crate::errors::HealthManagerError::ResearchProposalAlreadyQueued
+        );
+        require!(
+            self.can_queue(),
+            crate::errors::HealthManagerError::InsufficientResearchVotes
+        );
+
+        self.queued_at = Clock::get()?.unix_timestamp;
+
+        Ok(())
     }
 
+    /// Approve a queued proposal once its timelock has elapsed; voting may
+    /// still be open after an early queue, so the tally is checked again
     pub fn execute(&mut self) -> Result<()> {
         require!(
-            self.can_execute(),
+            !self.is_finalized(),
+            crate::errors::HealthManagerError::ResearchProposalFinalized
+        );
+        require!(
+            self.is_queued(),
+            crate::errors::HealthManagerError::ResearchProposalNotQueued
+        );
+        require!(
+            Clock::get()?.unix_timestamp >= self.executable_at(),
+            crate::errors::HealthManagerError::ExecutionTimelockActive
+        );
+        require!(
+            self.has_passed(),
             crate::errors::HealthManagerError::InsufficientResearchVotes
         );
 
@@ -108,10 +594,298 @@ impl ResearchProposal {
 
         Ok(())
     }
+
+    /// Record a failed proposal as rejected once voting has closed
+    pub fn finalize(&mut self) -> Result<()> {
+        require!(
+            !self.is_finalized(),
+            crate::errors::HealthManagerError::ResearchProposalFinalized
+        );
+        require!(
+            self.is_expired(),
+            crate::errors::HealthManagerError::VotingPeriodActive
+        );
+        require!(
+            !self.has_passed(),
+            crate::errors::HealthManagerError::ResearchProposalPassed
+        );
+
+        self.is_rejected = true;
+
+        Ok(())
+    }
+
+    /// Withdraw the proposal before anyone has voted
+    pub fn cancel(&mut self) -> Result<()> {
+        require!(
+            !self.is_finalized(),
+            crate::errors::HealthManagerError::ResearchProposalFinalized
+        );
+        require!(
+            self.total_voters == 0,
+            crate::errors::HealthManagerError::ResearchProposalHasVotes
+        );
+
+        self.is_cancelled = true;
+
+        Ok(())
+    }
 }
 
 #[account]
 pub struct ResearchVote {
+    /// Proposal being voted on
+    pub proposal_id: u64,
+    /// Voter whose weight this vote carries
+    pub voter: Pubkey,
+    /// Who cast the vote (the voter, or their delegate)
+    pub cast_by: Pubkey,
+    /// The vote (true = yes, false = no)
+    pub vote: bool,
+    /// Timestamp when vote was cast or last changed
+    pub voted_at: i64,
+    /// Who paid rent for the vote account and is refunded when it closes
+    pub rent_payer: Pubkey,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl ResearchVote {
+    pub const LEN: usize = 8 + // discriminator
+        8 +  // proposal_id
+        32 + // voter
+        32 + // cast_by
+        1 +  // vote
+        8 +  // voted_at
+        32 + // rent_payer
+        1;   // bump
+
+    pub fn new(
+        proposal_id: u64,
+        voter: Pubkey,
+        cast_by: Pubkey,
+        vote: bool,
+        bump: u8,
+    ) -> Result<Self> {
+        Ok(Self {
+            proposal_id,
+            voter,
+            cast_by,
+            vote,
+            voted_at: Clock::get()?.unix_timestamp,
+            // Whoever casts the first vote pays for the account
+            rent_payer: cast_by,
+            bump,
+        })
+    }
+
+    pub fn change(&mut self, vote: bool, cast_by: Pubkey) -> Result<()> {
+        self.vote = vote;
+        self.cast_by = cast_by;
+        self.voted_at = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+
+    /// Whether the voter cast (or took over) this vote themselves
+    pub fn is_direct(&self) -> bool {
+        self.cast_by == self.voter
+    }
+}
+
+#[account]
+pub struct VoteDelegation {
+    /// Profile holder delegating their research votes
+    pub delegator: Pubkey,
+    /// Delegate voting on the delegator's behalf
+    pub delegate: Pubkey,
+    /// Timestamp when delegation was created
+    pub created_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl VoteDelegation {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // delegator
+        32 + // delegate
+        8 +  // created_at
+        1;   // bump
+
+    pub fn new(
+        delegator: Pubkey,
+        delegate: Pubkey,
+        bump: u8,
+    ) -> Result<Self> {
+        require!(
+            delegator != delegate,
+            crate::errors::HealthManagerError::CannotGrantAccessToSelf
+        );
+
+        Ok(Self {
+            delegator,
+            delegate,
+            created_at: Clock::get()?.unix_timestamp,
+            bump,
+        })
+    }
+}
+
+#[account]
+pub struct ParameterChangeProposal {
+    /// Unique proposal ID
+    pub proposal_id: u64,
+    /// Profile holder proposing the change
+    pub proposer: Pubkey,
+    /// Proposed quorum
+    pub quorum_votes: u64,
+    /// Proposed approval threshold in basis points
+    pub approval_threshold_bps: u16,
+    /// Proposed voting period in seconds
+    pub voting_period: i64,
+    /// Proposed execution timelock in seconds
+    pub execution_delay: i64,
+    /// Proposed research proposal deposit (lamports)
+    pub proposal_deposit: u64,
+    /// Votes and execution state
+    pub tally: ProposalTally,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl ParameterChangeProposal {
+    pub const LEN: usize = 8 + // discriminator
+        8 +  // proposal_id
+        32 + // proposer
+        8 +  // quorum_votes
+        2 +  // approval_threshold_bps
+        8 +  // voting_period
+        8 +  // execution_delay
+        8 +  // proposal_deposit
+        ProposalTally::LEN + // tally
+        1;   // bump
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        proposal_id: u64,
+        proposer: Pubkey,
+        quorum_votes: u64,
+        approval_threshold_bps: u16,
+        voting_period: i64,
+        execution_delay: i64,
+        proposal_deposit: u64,
+        config: &GovernanceConfig,
+        bump: u8,
+    ) -> Result<Self> {
+        GovernanceConfig::validate_params(
+            quorum_votes,
+            approval_threshold_bps,
+            voting_period,
+            execution_delay,
+        )?;
+
+        Ok(Self {
+            proposal_id,
+            proposer,
+            quorum_votes,
+            approval_threshold_bps,
+            voting_period,
+            execution_delay,
+            proposal_deposit,
+            tally: ProposalTally::new(config)?,
+            bump,
+        })
+    }
+
+    pub fn cast_vote(&mut self, vote: bool) -> Result<()> {
+        require!(
+            !self.tally.is_expired(),
+            crate::errors::HealthManagerError::ParameterProposalExpired
+        );
+
+        self.tally.record_vote(vote);
+        Ok(())
+    }
+
+    pub fn execute(&mut self, config: &mut GovernanceConfig) -> Result<()> {
+        self.tally.execute()?;
+
+        config.apply(
+            self.quorum_votes,
+            self.approval_threshold_bps,
+            self.voting_period,
+            self.execution_delay,
+            self.proposal_deposit,
+        )
+    }
+}
+
+#[account]
+pub struct TreasuryWithdrawalProposal {
+    /// Unique proposal ID
+    pub proposal_id: u64,
+    /// Profile holder proposing the withdrawal
+    pub proposer: Pubkey,
+    /// Account that receives the lamports
+    pub recipient: Pubkey,
+    /// Lamports to withdraw from the treasury
+    pub amount: u64,
+    /// Votes and execution state
+    pub tally: ProposalTally,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl TreasuryWithdrawalProposal {
+    pub const LEN: usize = 8 + // discriminator
+        8 +  // proposal_id
+        32 + // proposer
+        32 + // recipient
+        8 +  // amount
+        ProposalTally::LEN + // tally
+        1;   // bump
+
+    pub fn new(
+        proposal_id: u64,
+        proposer: Pubkey,
+        recipient: Pubkey,
+        amount: u64,
+        config: &GovernanceConfig,
+        bump: u8,
+    ) -> Result<Self> {
+        require!(amount > 0, crate::errors::HealthManagerError::InvalidTokenAmount);
+
+        Ok(Self {
+            proposal_id,
+            proposer,
+            recipient,
+            amount,
+            tally: ProposalTally::new(config)?,
+            bump,
+        })
+    }
+
+    pub fn cast_vote(&mut self, vote: bool) -> Result<()> {
+        require!(
+            !self.tally.is_expired(),
+            crate::errors::HealthManagerError::TreasuryProposalExpired
+        );
+
+        self.tally.record_vote(vote);
+        Ok(())
+    }
+
+    /// Mark the withdrawal executed; the caller moves the lamports
+    pub fn execute(&mut self, treasury: &mut GovernanceTreasury) -> Result<()> {
+        self.tally.execute()?;
+
+        treasury.total_withdrawn += self.amount;
+        Ok(())
+    }
+}
+
+/// One voter's vote on a parameter, reward schedule or treasury proposal
+#[account]
+pub struct GovernanceVote {
     /// Proposal being voted on
     pub proposal_id: u64,
     /// Voter's public key
@@ -124,7 +898,7 @@ pub struct ResearchVote {
     pub bump: u8,
 }
 
-impl ResearchVote {
+impl GovernanceVote {
     pub const LEN: usize = 8 + // discriminator
         8 +  // proposal_id
         32 + // voter
@@ -146,4 +920,163 @@ impl ResearchVote {
             bump,
         })
     }
-}
\ No newline at end of file
+}
+
+#[account]
+pub struct ResearchConsent {
+    /// Proposal the consent applies to
+    pub proposal_id: u64,
+    /// Data owner giving consent
+    pub owner: Pubkey,
+    /// Record types covered by the consent (empty = all record types)
+    pub record_types: Vec<String>,
+    /// Whether the consent has been withdrawn
+    pub is_withdrawn: bool,
+    /// Timestamp when consent was given
+    pub granted_at: i64,
+    /// Timestamp when consent was withdrawn (0 if still active)
+    pub withdrawn_at: i64,
+    /// Number of distinct records of this owner accessed for research
+    pub records_accessed: u64,
+    /// Number of distinct records already compensated
+    pub records_compensated: u64,
+    /// Whether the per-participant payout has been claimed
+    pub participant_compensated: bool,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl ResearchConsent {
+    pub const LEN: usize = 8 + // discriminator
+        8 +  // proposal_id
+        32 + // owner
+        4 + MAX_RESEARCH_RECORD_TYPES * (4 + MAX_RECORD_TYPE_LENGTH) + // record_types
+        1 +  // is_withdrawn
+        8 +  // granted_at
+        8 +  // withdrawn_at
+        8 +  // records_accessed
+        8 +  // records_compensated
+        1 +  // participant_compensated
+        1;   // bump
+
+    pub fn new(
+        proposal_id: u64,
+        owner: Pubkey,
+        record_types: Vec<String>,
+        bump: u8,
+    ) -> Result<Self> {
+        Self::validate_record_types(&record_types)?;
+
+        Ok(Self {
+            proposal_id,
+            owner,
+            record_types,
+            is_withdrawn: false,
+            granted_at: Clock::get()?.unix_timestamp,
+            withdrawn_at: 0,
+            records_accessed: 0,
+            records_compensated: 0,
+            participant_compensated: false,
+            bump,
+        })
+    }
+
+    /// Whether the consent account was just created
+    pub fn is_new(&self) -> bool {
+        self.granted_at == 0
+    }
+
+    /// Grant consent again after it was withdrawn. Records already accessed
+    /// keep their markers, so only the ones not yet compensated carry over.
+    pub fn regrant(&mut self, record_types: Vec<String>) -> Result<()> {
+        require!(
+            self.is_withdrawn,
+            crate::errors::HealthManagerError::ResearchConsentActive
+        );
+        Self::validate_record_types(&record_types)?;
+
+        self.record_types = record_types;
+        self.is_withdrawn = false;
+        self.granted_at = Clock::get()?.unix_timestamp;
+        self.withdrawn_at = 0;
+        self.records_accessed -= self.records_compensated;
+        self.records_compensated = 0;
+        Ok(())
+    }
+
+    fn validate_record_types(record_types: &[String]) -> Result<()> {
+        require!(
+            record_types.len() <= MAX_RESEARCH_RECORD_TYPES,
+            crate::errors::HealthManagerError::TooManyRecordTypes
+        );
+        require!(
+            record_types.iter().all(|t| t.len() <= MAX_RECORD_TYPE_LENGTH),
+            crate::errors::HealthManagerError::RecordTypeTooLong
+        );
+        Ok(())
+    }
+
+    pub fn withdraw(&mut self) -> Result<()> {
+        require!(
+            !self.is_withdrawn,
+            crate::errors::HealthManagerError::ResearchConsentWithdrawn
+        );
+
+        self.is_withdrawn = true;
+        self.withdrawn_at = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+
+    pub fn covers_record_type(&self, record_type: &str) -> bool {
+        self.record_types.is_empty() || self.record_types.iter().any(|t| t == record_type)
+    }
+
+    pub fn record_access(&mut self) {
+        self.records_accessed += 1;
+    }
+
+    pub fn mark_compensated(&mut self) {
+        self.records_compensated = self.records_accessed;
+        self.participant_compensated = true;
+    }
+}
+
+/// Marks a record as accessed under a research proposal, so repeated reads
+/// of the same record are only compensated once
+#[account]
+pub struct ResearchRecordAccess {
+    /// Proposal the access happened under
+    pub proposal_id: u64,
+    /// Owner of the accessed record
+    pub owner: Pubkey,
+    /// Accessed record
+    pub record_id: u64,
+    /// Timestamp of the first research access (0 until counted)
+    pub first_accessed_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl ResearchRecordAccess {
+    pub const LEN: usize = 8 + // discriminator
+        8 +  // proposal_id
+        32 + // owner
+        8 +  // record_id
+        8 +  // first_accessed_at
+        1;   // bump
+
+    pub fn new(proposal_id: u64, owner: Pubkey, record_id: u64, bump: u8) -> Result<Self> {
+        Ok(Self {
+            proposal_id,
+            owner,
+            record_id,
+            first_accessed_at: Clock::get()?.unix_timestamp,
+            bump,
+        })
+    }
+
+    /// Whether the marker was just created by `init_if_needed`
+    pub fn is_new(&self) -> bool {
+        self.first_accessed_at == 0
+    }
+}
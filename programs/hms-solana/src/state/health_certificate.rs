@@ -0,0 +1,188 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::state::HealthRecord;
+
+#[account]
+pub struct CertificateIssuer {
+    /// Issuer's public key (clinic, lab, vaccination centre)
+    pub issuer: Pubkey,
+    /// Display name of the issuer
+    pub name: String,
+    /// Whether certificates from this issuer are still accepted
+    pub is_active: bool,
+    /// Timestamp when the issuer was registered
+    pub registered_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl CertificateIssuer {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // issuer
+        4 + MAX_INSTITUTION_LENGTH + // name
+        1 +  // is_active
+        8 +  // registered_at
+        1;   // bump
+
+    pub fn new(
+        issuer: Pubkey,
+        name: String,
+        bump: u8,
+    ) -> Result<Self> {
+        require!(
+            name.len() <= MAX_INSTITUTION_LENGTH,
+            crate::errors::HealthManagerError::InstitutionTooLong
+        );
+
+        Ok(Self {
+            issuer,
+            name,
+            is_active: true,
+            registered_at: Clock::get()?.unix_timestamp,
+            bump,
+        })
+    }
+
+    pub fn revoke(&mut self) {
+        self.is_active = false;
+    }
+}
+
+#[account]
+pub struct HealthCertificate {
+    /// Mint of the certificate NFT
+    pub mint: Pubkey,
+    /// Registered issuer that signed the certificate
+    pub issuer: Pubkey,
+    /// Patient's health profile
+    pub profile: Pubkey,
+    /// Patient the certificate was issued to
+    pub patient: Pubkey,
+    /// Health record the certificate attests to
+    pub record: Pubkey,
+    /// `data_hash` of the record at the time of issuance
+    pub data_hash: [u8; 32],
+    /// Kind of certificate (e.g., "vaccination", "test_result")
+    pub certificate_type: String,
+    /// Timestamp when the certificate was issued
+    pub issued_at: i64,
+    /// Timestamp after which the certificate is no longer valid
+    pub expires_at: i64,
+    /// Whether the issuer has revoked the certificate
+    pub is_revoked: bool,
+    /// Timestamp when the certificate was revoked (0 if not revoked)
+    pub revoked_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl HealthCertificate {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // mint
+        32 + // issuer
+        32 + // profile
+        32 + // patient
+        32 + // record
+        32 + // data_hash
+        4 + MAX_CERTIFICATE_TYPE_LENGTH + // certificate_type
+        8 +  // issued_at
+        8 +  // expires_at
+        1 +  // is_revoked
+        8 +  // revoked_at
+        1;   // bump
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        mint: Pubkey,
+        issuer: Pubkey,
+        profile: Pubkey,
+        patient: Pubkey,
+        record: Pubkey,
+        data_hash: [u8; 32],
+        certificate_type: String,
+        expires_at: i64,
+        bump: u8,
+    ) -> Result<Self> {
+        require!(
+            certificate_type.len() <= MAX_CERTIFICATE_TYPE_LENGTH,
+            crate::errors::HealthManagerError::CertificateTypeTooLong
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(
+            expires_at > now,
+            crate::errors::HealthManagerError::InvalidTimestamp
+        );
+
+        Ok(Self {
+            mint,
+            issuer,
+            profile,
+            patient,
+            record,
+            data_hash,
+            certificate_type,
+            issued_at: now,
+            expires_at,
+            is_revoked: false,
+            revoked_at: 0,
+            bump,
+        })
+    }
+
+    pub fn revoke(&mut self) -> Result<()> {
+        require!(
+            !self.is_revoked,
+            crate::errors::HealthManagerError::CertificateAlreadyRevoked
+        );
+
+        self.is_revoked = true;
+        self.revoked_at = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+
+    /// Check the certificate against its issuer's current registration and
+    /// the current state of the attested record
+    pub fn status(
+        &self,
+        issuer: &CertificateIssuer,
+        record: &HealthRecord,
+    ) -> Result<CertificateStatus> {
+        let issuer_registered = issuer.is_active;
+        let is_expired = Clock::get()?.unix_timestamp >= self.expires_at;
+        let record_matches = record.is_accessible() && record.data_hash == self.data_hash;
+
+        Ok(CertificateStatus {
+            is_valid: issuer_registered && !self.is_revoked && !is_expired && record_matches,
+            issuer_registered,
+            is_revoked: self.is_revoked,
+            is_expired,
+            record_matches,
+            patient: self.patient,
+            data_hash: self.data_hash,
+            expires_at: self.expires_at,
+        })
+    }
+}
+
+/// Result of `verify_certificate`, returned to the caller as return data
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CertificateStatus {
+    /// True only if every check below passes
+    pub is_valid: bool,
+    /// Whether the issuer is still registered
+    pub issuer_registered: bool,
+    /// Whether the issuer revoked the certificate
+    pub is_revoked: bool,
+    /// Whether the certificate is past its expiry
+    pub is_expired: bool,
+    /// Whether the record is still accessible and has the certified `data_hash`
+    pub record_matches: bool,
+    /// Patient the certificate belongs to
+    pub patient: Pubkey,
+    /// `data_hash` of the attested health record
+    pub data_hash: [u8; 32],
+    /// Timestamp after which the certificate is no longer valid
+    pub expires_at: i64,
+}
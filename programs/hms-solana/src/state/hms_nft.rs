@@ -1,6 +1,64 @@
 use anchor_lang::prelude::*;
+use crate::constants::*;
 
 #[account]
 pub struct HmsNft {
-    pub authority: Pubkey,
+    /// Mint of the NFT
+    pub mint: Pubkey,
+    /// Health profile the NFT is tied to
+    pub profile: Pubkey,
+    /// Owner of the health profile
+    pub owner: Pubkey,
+    /// What the NFT represents (e.g., "health_passport")
+    pub purpose: String,
+    /// Whether the token account was frozen at mint (non-transferable)
+    pub is_soulbound: bool,
+    /// Mint of the replacement NFT if this one was re-issued to a new wallet
+    pub reissued_to: Option<Pubkey>,
+    /// Timestamp when the NFT was minted
+    pub minted_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl HmsNft {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // mint
+        32 + // profile
+        32 + // owner
+        4 + MAX_NFT_PURPOSE_LENGTH + // purpose
+        1 +  // is_soulbound
+        1 + 32 + // reissued_to
+        8 +  // minted_at
+        1;   // bump
+
+    pub fn new(
+        mint: Pubkey,
+        profile: Pubkey,
+        owner: Pubkey,
+        purpose: String,
+        is_soulbound: bool,
+        bump: u8,
+    ) -> Result<Self> {
+        require!(
+            purpose.len() <= MAX_NFT_PURPOSE_LENGTH,
+            crate::errors::HealthManagerError::NftPurposeTooLong
+        );
+
+        Ok(Self {
+            mint,
+            profile,
+            owner,
+            purpose,
+            is_soulbound,
+            reissued_to: None,
+            minted_at: Clock::get()?.unix_timestamp,
+            bump,
+        })
+    }
+
+    /// Mark this NFT as superseded by a replacement minted to a new wallet
+    pub fn reissue(&mut self, new_mint: Pubkey) {
+        self.reissued_to = Some(new_mint);
+    }
 }
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct HmsTokenConfig {
+    /// HMS utility token mint; this account is its mint authority
+    pub mint: Pubkey,
+    /// Key allowed to reward data contributions
+    pub authority: Pubkey,
+    /// Tokens (base units) minted per rewarded contribution
+    pub reward_per_contribution: u64,
+    /// Total tokens minted as contribution rewards
+    pub total_rewarded: u64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl HmsTokenConfig {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // mint
+        32 + // authority
+        8 +  // reward_per_contribution
+        8 +  // total_rewarded
+        1;   // bump
+
+    pub fn new(
+        mint: Pubkey,
+        authority: Pubkey,
+        reward_per_contribution: u64,
+        bump: u8,
+    ) -> Result<Self> {
+        require!(
+            reward_per_contribution > 0,
+            crate::errors::HealthManagerError::InvalidTokenAmount
+        );
+
+        Ok(Self {
+            mint,
+            authority,
+            reward_per_contribution,
+            total_rewarded: 0,
+            bump,
+        })
+    }
+
+    pub fn record_reward(&mut self, amount: u64) {
+        self.total_rewarded += amount;
+    }
+}
+
+#[account]
+pub struct ContributionReward {
+    /// Health record or wearable data batch that was rewarded
+    pub record: Pubkey,
+    /// Owner of the contribution who received the reward
+    pub contributor: Pubkey,
+    /// Tokens (base units) minted
+    pub amount: u64,
+    /// Timestamp when the reward was paid
+    pub rewarded_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl ContributionReward {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // record
+        32 + // contributor
+        8 +  // amount
+        8 +  // rewarded_at
+        1;   // bump
+
+    pub fn new(
+        record: Pubkey,
+        contributor: Pubkey,
+        amount: u64,
+        bump: u8,
+    ) -> Result<Self> {
+        Ok(Self {
+            record,
+            contributor,
+            amount,
+            rewarded_at: Clock::get()?.unix_timestamp,
+            bump,
+        })
+    }
+}
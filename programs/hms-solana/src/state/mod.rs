@@ -4,9 +4,15 @@ pub mod access;
 pub mod zk_proof;
 pub mod emergency_access;
 pub mod dao_governance;
+pub mod researcher_credential;
+pub mod research_compensation;
 pub mod wearable_device;
 pub mod sync_state;
 pub mod hms_nft;
+pub mod health_certificate;
+pub mod hms_token;
+pub mod access_ticket;
+pub mod contribution_rewards;
 
 pub use profile::*;
 pub use record::*;
@@ -14,6 +20,12 @@ pub use access::*;
 pub use zk_proof::*;
 pub use emergency_access::*;
 pub use dao_governance::*;
+pub use researcher_credential::*;
+pub use research_compensation::*;
 pub use wearable_device::*;
 pub use sync_state::*;
-pub use hms_nft::*;
\ No newline at end of file
+pub use hms_nft::*;
+pub use health_certificate::*;
+pub use hms_token::*;
+pub use access_ticket::*;
+pub use contribution_rewards::*;
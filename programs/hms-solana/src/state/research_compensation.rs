@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+
+#[account]
+pub struct ResearchCompensation {
+    /// Proposal the compensation pool belongs to
+    pub proposal_id: u64,
+    /// Researcher who funded the pool
+    pub researcher: Pubkey,
+    /// SPL mint payouts are made in
+    pub mint: Pubkey,
+    /// Token account holding the escrowed funds
+    pub vault: Pubkey,
+    /// Payout for each distinct record of a data owner accessed for research
+    pub payout_per_record: u64,
+    /// One-off payout for each data owner whose records were accessed
+    pub payout_per_participant: u64,
+    /// Total amount deposited into the vault
+    pub total_funded: u64,
+    /// Total amount paid out to data owners
+    pub total_paid: u64,
+    /// Timestamp after which the researcher may reclaim the remainder
+    pub claim_deadline: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl ResearchCompensation {
+    pub const LEN: usize = 8 + // discriminator
+        8 +  // proposal_id
+        32 + // researcher
+        32 + // mint
+        32 + // vault
+        8 +  // payout_per_record
+        8 +  // payout_per_participant
+        8 +  // total_funded
+        8 +  // total_paid
+        8 +  // claim_deadline
+        1;   // bump
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        proposal_id: u64,
+        researcher: Pubkey,
+        mint: Pubkey,
+        vault: Pubkey,
+        amount: u64,
+        payout_per_record: u64,
+        payout_per_participant: u64,
+        access_expires_at: i64,
+        bump: u8,
+    ) -> Result<Self> {
+        require!(
+            amount > 0 && (payout_per_record > 0 || payout_per_participant > 0),
+            crate::errors::HealthManagerError::InvalidCompensationAmount
+        );
+
+        Ok(Self {
+            proposal_id,
+            researcher,
+            mint,
+            vault,
+            payout_per_record,
+            payout_per_participant,
+            total_funded: amount,
+            total_paid: 0,
+            claim_deadline: access_expires_at + COMPENSATION_CLAIM_WINDOW,
+            bump,
+        })
+    }
+
+    /// Amount owed for distinct records accessed but not yet compensated
+    pub fn amount_owed(
+        &self,
+        records_accessed: u64,
+        records_compensated: u64,
+        participant_compensated: bool,
+    ) -> Result<u64> {
+        let new_records = records_accessed.saturating_sub(records_compensated);
+        let mut amount = new_records
+            .checked_mul(self.payout_per_record)
+            .ok_or(crate::errors::HealthManagerError::InvalidCompensationAmount)?;
+
+        if records_accessed > 0 && !participant_compensated {
+            amount = amount
+                .checked_add(self.payout_per_participant)
+                .ok_or(crate::errors::HealthManagerError::InvalidCompensationAmount)?;
+        }
+
+        Ok(amount)
+    }
+
+    pub fn record_payout(&mut self, amount: u64) {
+        self.total_paid += amount;
+    }
+
+    pub fn is_claim_window_over(&self) -> bool {
+        let now = Clock::get().unwrap().unix_timestamp;
+        now >= self.claim_deadline
+    }
+}
@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+
+#[account]
+pub struct ResearchAttestor {
+    /// Attestor's public key (ethics board, institution)
+    pub attestor: Pubkey,
+    /// Display name of the attestor
+    pub name: String,
+    /// Whether the attestor may still vouch for researchers
+    pub is_active: bool,
+    /// Timestamp when attestor was registered
+    pub registered_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl ResearchAttestor {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // attestor
+        4 + MAX_INSTITUTION_LENGTH + // name
+        1 +  // is_active
+        8 +  // registered_at
+        1;   // bump
+
+    pub fn new(
+        attestor: Pubkey,
+        name: String,
+        bump: u8,
+    ) -> Result<Self> {
+        require!(
+            name.len() <= MAX_INSTITUTION_LENGTH,
+            crate::errors::HealthManagerError::InstitutionTooLong
+        );
+
+        Ok(Self {
+            attestor,
+            name,
+            is_active: true,
+            registered_at: Clock::get()?.unix_timestamp,
+            bump,
+        })
+    }
+
+    pub fn revoke(&mut self) {
+        self.is_active = false;
+    }
+}
+
+#[account]
+pub struct ResearcherCredential {
+    /// Credentialed researcher
+    pub researcher: Pubkey,
+    /// Attestor that issued the credential
+    pub attestor: Pubkey,
+    /// Researcher's institution
+    pub institution: String,
+    /// Hash of the IRB / ethics approval document
+    pub irb_approval_hash: [u8; 32],
+    /// Timestamp when the credential was issued
+    pub issued_at: i64,
+    /// Timestamp when the IRB approval lapses
+    pub expires_at: i64,
+    /// Whether the attestor has revoked the credential
+    pub is_revoked: bool,
+    /// Timestamp when the credential was revoked (0 if not revoked)
+    pub revoked_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl ResearcherCredential {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // researcher
+        32 + // attestor
+        4 + MAX_INSTITUTION_LENGTH + // institution
+        32 + // irb_approval_hash
+        8 +  // issued_at
+        8 +  // expires_at
+        1 +  // is_revoked
+        8 +  // revoked_at
+        1;   // bump
+
+    pub fn new(
+        researcher: Pubkey,
+        attestor: Pubkey,
+        institution: String,
+        irb_approval_hash: [u8; 32],
+        expires_at: i64,
+        bump: u8,
+    ) -> Result<Self> {
+        require!(
+            institution.len() <= MAX_INSTITUTION_LENGTH,
+            crate::errors::HealthManagerError::InstitutionTooLong
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(
+            expires_at > now,
+            crate::errors::HealthManagerError::InvalidTimestamp
+        );
+
+        Ok(Self {
+            researcher,
+            attestor,
+            institution,
+            irb_approval_hash,
+            issued_at: now,
+            expires_at,
+            is_revoked: false,
+            revoked_at: 0,
+            bump,
+        })
+    }
+
+    pub fn revoke(&mut self) -> Result<()> {
+        self.is_revoked = true;
+        self.revoked_at = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+
+    pub fn is_valid(&self) -> bool {
+        let now = Clock::get().unwrap().unix_timestamp;
+        !self.is_revoked && now < self.expires_at
+    }
+
+    /// The issuing attestor can always renew. Another attestor can only take
+    /// over a credential that lapsed, so a revocation is never undone by them.
+    pub fn can_be_renewed_by(&self, attestor: Pubkey) -> bool {
+        self.attestor == attestor || (!self.is_revoked && !self.is_valid())
+    }
+}
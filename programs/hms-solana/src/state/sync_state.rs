@@ -1,17 +1,225 @@
 use anchor_lang::prelude::*;
+use solana_sha256_hasher::hash;
 use crate::constants::*;
 
+/// How a sync source's version vector relates to the target's
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SyncRelation {
+    /// Both devices have seen exactly the same updates
+    InSync,
+    /// The source has seen everything the target has, and more
+    FastForward,
+    /// The target has seen everything the source has, and more
+    Stale,
+    /// Each device has updates the other has not seen
+    Concurrent,
+}
+
+#[account]
+pub struct SyncRegistry {
+    /// Owner whose devices are tracked
+    pub owner: Pubkey,
+    /// Device ID of the current primary sync device (empty = none)
+    pub primary_device: String,
+    /// Number of registered sync devices
+    pub device_count: u64,
+    /// Bitmask of device slots in use
+    pub active_slots: u8,
+    /// Head of a hash chain over the shared sync key: each use reveals the
+    /// preimage of the head, which then becomes the new head, so the raw key
+    /// never leaves the owner's devices and a revealed proof cannot be replayed
+    pub sync_key_commitment: [u8; 32],
+    /// Sync key generation; bumped on every rotation so operations started
+    /// under an older key can no longer complete
+    pub key_epoch: u64,
+    /// Set when a device is revoked; cleared once every device has moved to
+    /// a key the revoked one never saw
+    pub key_rotation_required: bool,
+    /// Seconds a sync operation may stay pending before anyone can fail it
+    pub operation_timeout: i64,
+    /// Version vector of each device slot: entry [d][s] counts the updates
+    /// originating from slot s that device d has seen
+    pub version_vectors: [[u64; MAX_SYNC_DEVICES]; MAX_SYNC_DEVICES],
+    /// Timestamp when registry was created
+    pub created_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl SyncRegistry {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // owner
+        4 + MAX_DEVICE_ID_LENGTH + // primary_device
+        8 +  // device_count
+        1 +  // active_slots
+        32 + // sync_key_commitment
+        8 +  // key_epoch
+        1 +  // key_rotation_required
+        8 +  // operation_timeout
+        8 * MAX_SYNC_DEVICES * MAX_SYNC_DEVICES + // version_vectors
+        8 +  // created_at
+        1;   // bump
+
+    pub fn new(owner: Pubkey, sync_key_commitment: [u8; 32], bump: u8) -> Result<Self> {
+        Ok(Self {
+            owner,
+            primary_device: String::new(),
+            device_count: 0,
+            active_slots: 0,
+            sync_key_commitment,
+            key_epoch: 0,
+            key_rotation_required: false,
+            operation_timeout: DEFAULT_SYNC_OPERATION_TIMEOUT,
+            version_vectors: [[0u64; MAX_SYNC_DEVICES]; MAX_SYNC_DEVICES],
+            created_at: Clock::get()?.unix_timestamp,
+            bump,
+        })
+    }
+
+    pub fn has_primary(&self) -> bool {
+        !self.primary_device.is_empty()
+    }
+
+    pub fn is_primary(&self, device_id: &str) -> bool {
+        self.has_primary() && self.primary_device == device_id
+    }
+
+    /// Register a device and hand out its version vector slot
+    pub fn register_device(&mut self, device_id: &str, is_primary: bool) -> Result<u8> {
+        let slot = (0..MAX_SYNC_DEVICES)
+            .find(|slot| self.active_slots & (1 << slot) == 0)
+            .ok_or(crate::errors::HealthManagerError::TooManySyncDevices)?;
+
+        if is_primary {
+            require!(
+                !self.has_primary(),
+                crate::errors::HealthManagerError::PrimaryDeviceExists
+            );
+            self.primary_device = device_id.to_string();
+        }
+
+        // A reused slot starts from scratch: the new device has seen nothing,
+        // and updates from the revoked device it replaces are forgotten
+        self.version_vectors[slot] = [0u64; MAX_SYNC_DEVICES];
+        for vector in self.version_vectors.iter_mut() {
+            vector[slot] = 0;
+        }
+
+        self.active_slots |= 1 << slot;
+        self.device_count += 1;
+        Ok(slot as u8)
+    }
+
+    /// Free a revoked device's slot and require a new sync key
+    pub fn revoke_device(&mut self, device_id: &str, slot: u8) {
+        if self.is_primary(device_id) {
+            self.clear_primary();
+        }
+        self.active_slots &= !(1 << slot);
+        self.device_count -= 1;
+        self.key_rotation_required = true;
+    }
+
+    /// Check a proof of the sync key against the stored commitment and
+    /// consume it, advancing the hash chain by one link
+    pub fn verify_sync_key(&mut self, sync_key_proof: &[u8; 32]) -> Result<()> {
+        require!(
+            !self.key_rotation_required,
+            crate::errors::HealthManagerError::SyncKeyRotationRequired
+        );
+        require!(
+            hash(sync_key_proof).to_bytes() == self.sync_key_commitment,
+            crate::errors::HealthManagerError::SyncKeyMismatch
+        );
+
+        self.sync_key_commitment = *sync_key_proof;
+        Ok(())
+    }
+
+    /// Move every device to a new sync key at once
+    pub fn rotate_sync_key(&mut self, new_commitment: [u8; 32]) -> Result<()> {
+        require!(
+            new_commitment != self.sync_key_commitment,
+            crate::errors::HealthManagerError::InvalidSyncKey
+        );
+
+        self.sync_key_commitment = new_commitment;
+        self.key_epoch += 1;
+        self.key_rotation_required = false;
+        Ok(())
+    }
+
+    /// Whether an operation started under `key_epoch` may still complete
+    pub fn is_current_key(&self, key_epoch: u64) -> bool {
+        !self.key_rotation_required && self.key_epoch == key_epoch
+    }
+
+    pub fn set_primary(&mut self, device_id: &str) {
+        self.primary_device = device_id.to_string();
+    }
+
+    pub fn set_operation_timeout(&mut self, operation_timeout: i64) -> Result<()> {
+        require!(
+            operation_timeout > 0 && operation_timeout <= MAX_SYNC_OPERATION_TIMEOUT,
+            crate::errors::HealthManagerError::InvalidSyncTimeout
+        );
+
+        self.operation_timeout = operation_timeout;
+        Ok(())
+    }
+
+    pub fn clear_primary(&mut self) {
+        self.primary_device = String::new();
+    }
+
+    /// Count a local change made on a device
+    pub fn record_local_update(&mut self, slot: u8) {
+        let slot = slot as usize;
+        self.version_vectors[slot][slot] += 1;
+    }
+
+    pub fn compare(&self, source_slot: u8, target_slot: u8) -> SyncRelation {
+        let source = &self.version_vectors[source_slot as usize];
+        let target = &self.version_vectors[target_slot as usize];
+        let source_ahead = source.iter().zip(target.iter()).any(|(s, t)| s > t);
+        let target_ahead = source.iter().zip(target.iter()).any(|(s, t)| t > s);
+
+        match (source_ahead, target_ahead) {
+            (false, false) => SyncRelation::InSync,
+            (true, false) => SyncRelation::FastForward,
+            (false, true) => SyncRelation::Stale,
+            (true, true) => SyncRelation::Concurrent,
+        }
+    }
+
+    /// After a sync both devices have seen the union of their updates; a
+    /// conflict resolution is itself a new update from the source
+    pub fn merge(&mut self, source_slot: u8, target_slot: u8, resolves_conflict: bool) {
+        let (source_slot, target_slot) = (source_slot as usize, target_slot as usize);
+        let mut merged = [0u64; MAX_SYNC_DEVICES];
+        for (slot, version) in merged.iter_mut().enumerate() {
+            *version = self.version_vectors[source_slot][slot].max(self.version_vectors[target_slot][slot]);
+        }
+        if resolves_conflict {
+            merged[source_slot] += 1;
+        }
+
+        self.version_vectors[source_slot] = merged;
+        self.version_vectors[target_slot] = merged;
+    }
+}
+
 #[account]
 pub struct SyncState {
     /// Owner of the sync state
     pub owner: Pubkey,
     /// Device identifier for this sync state
     pub device_id: String,
+    /// Slot of this device's version vector in the sync registry
+    pub device_slot: u8,
     /// Current sync version/sequence number
     pub sync_version: u64,
-    /// Encrypted sync key for cross-device encryption
-    pub encrypted_sync_key: [u8; MAX_SYNC_KEY_LENGTH],
-    /// Hash of the current state for conflict detection
+    /// Hash of the current state, checked by clients after a sync
     pub state_hash: [u8; 32],
     /// Last successful sync timestamp
     pub last_sync_timestamp: i64,
@@ -19,6 +227,8 @@ pub struct SyncState {
     pub sync_count: u64,
     /// Whether this device is the primary sync source
     pub is_primary: bool,
+    /// ID that will be assigned to the next sync operation started from this device
+    pub next_operation_id: u64,
     /// Timestamp when sync state was created
     pub created_at: i64,
     /// PDA bump
@@ -29,19 +239,20 @@ impl SyncState {
     pub const LEN: usize = 8 + // discriminator
         32 + // owner
         4 + MAX_DEVICE_ID_LENGTH + // device_id
+        1 +  // device_slot
         8 +  // sync_version
-        MAX_SYNC_KEY_LENGTH + // encrypted_sync_key
         32 + // state_hash
         8 +  // last_sync_timestamp
         8 +  // sync_count
         1 +  // is_primary
+        8 +  // next_operation_id
         8 +  // created_at
         1;   // bump
 
     pub fn new(
         owner: Pubkey,
         device_id: String,
-        encrypted_sync_key: [u8; MAX_SYNC_KEY_LENGTH],
+        device_slot: u8,
         is_primary: bool,
         bump: u8,
     ) -> Result<Self> {
@@ -55,12 +266,13 @@ impl SyncState {
         Ok(Self {
             owner,
             device_id,
+            device_slot,
             sync_version: 1,
-            encrypted_sync_key,
             state_hash: [0u8; 32], // Will be updated on first sync
             last_sync_timestamp: 0,
             sync_count: 0,
             is_primary,
+            next_operation_id: 0,
             created_at: now,
             bump,
         })
@@ -80,21 +292,28 @@ impl SyncState {
         Ok(())
     }
 
-    pub fn validate_sync_key(&self, provided_key: &[u8; MAX_SYNC_KEY_LENGTH]) -> bool {
-        self.encrypted_sync_key == *provided_key
-    }
-
-    pub fn detect_conflict(&self, other_state_hash: [u8; 32]) -> bool {
-        self.state_hash != other_state_hash
+    /// Record a change made locally on this device
+    pub fn record_local_update(&mut self, new_state_hash: [u8; 32]) {
+        self.sync_version += 1;
+        self.state_hash = new_state_hash;
     }
 
     pub fn set_primary(&mut self, is_primary: bool) {
         self.is_primary = is_primary;
     }
+
+    /// Hand out the current operation ID and advance the counter
+    pub fn assign_next_operation(&mut self) -> u64 {
+        let operation_id = self.next_operation_id;
+        self.next_operation_id += 1;
+        operation_id
+    }
 }
 
 #[account]
 pub struct SyncOperation {
+    /// Sequence number of the operation among those started from the source device
+    pub operation_id: u64,
     /// Owner of the sync operation
     pub owner: Pubkey,
     /// Source device for the sync
@@ -107,6 +326,8 @@ pub struct SyncOperation {
     pub source_version: u64,
     /// Target sync version
     pub target_version: u64,
+    /// Registry key epoch the operation was started under
+    pub key_epoch: u64,
     /// Number of records synced
     pub records_synced: u64,
     /// Whether the operation completed successfully
@@ -115,6 +336,8 @@ pub struct SyncOperation {
     pub error_message: String,
     /// Timestamp when operation started
     pub started_at: i64,
+    /// Timestamp after which a pending operation can be failed by anyone
+    pub timeout_at: i64,
     /// Timestamp when operation completed
     pub completed_at: i64,
     /// PDA bump
@@ -123,26 +346,33 @@ pub struct SyncOperation {
 
 impl SyncOperation {
     pub const LEN: usize = 8 + // discriminator
+        8 +  // operation_id
         32 + // owner
         4 + MAX_DEVICE_ID_LENGTH + // source_device
         4 + MAX_DEVICE_ID_LENGTH + // target_device
         4 + MAX_RECORD_TYPE_LENGTH + // operation_type
         8 +  // source_version
         8 +  // target_version
+        8 +  // key_epoch
         8 +  // records_synced
         1 +  // is_successful
         4 + MAX_METADATA_LENGTH + // error_message
         8 +  // started_at
+        8 +  // timeout_at
         8 +  // completed_at
         1;   // bump
 
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
+        operation_id: u64,
         owner: Pubkey,
         source_device: String,
         target_device: String,
         operation_type: String,
         source_version: u64,
         target_version: u64,
+        key_epoch: u64,
+        operation_timeout: i64,
         bump: u8,
     ) -> Result<Self> {
         require!(
@@ -161,22 +391,43 @@ impl SyncOperation {
         let now = Clock::get()?.unix_timestamp;
 
         Ok(Self {
+            operation_id,
             owner,
             source_device,
             target_device,
             operation_type,
             source_version,
             target_version,
+            key_epoch,
             records_synced: 0,
             is_successful: false,
             error_message: String::new(),
             started_at: now,
+            timeout_at: now + operation_timeout,
             completed_at: 0,
             bump,
         })
     }
 
+    pub fn is_pending(&self) -> bool {
+        self.completed_at == 0
+    }
+
+    pub fn is_timed_out(&self) -> bool {
+        let now = Clock::get().unwrap().unix_timestamp;
+        self.is_pending() && now >= self.timeout_at
+    }
+
     pub fn complete_successfully(&mut self, records_synced: u64) -> Result<()> {
+        require!(
+            self.is_pending(),
+            crate::errors::HealthManagerError::InvalidSyncOperation
+        );
+        require!(
+            !self.is_timed_out(),
+            crate::errors::HealthManagerError::SyncOperationTimedOut
+        );
+
         let now = Clock::get()?.unix_timestamp;
 
         self.records_synced = records_synced;
@@ -187,6 +438,10 @@ impl SyncOperation {
     }
 
     pub fn complete_with_error(&mut self, error_message: String) -> Result<()> {
+        require!(
+            self.is_pending(),
+            crate::errors::HealthManagerError::InvalidSyncOperation
+        );
         require!(
             error_message.len() <= MAX_METADATA_LENGTH,
             crate::errors::HealthManagerError::MetadataTooLong
@@ -131,6 +131,7 @@ impl WearableDataBatch {
         1 +  // is_processed
         1;   // bump
 
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         owner: Pubkey,
         device_id: String,
@@ -2,7 +2,7 @@ import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { HmsSolana } from "../target/types/hms_solana";
 import { expect } from "chai";
-import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import { Keypair, PublicKey, SystemProgram, Transaction, TransactionInstruction } from "@solana/web3.js";
 import * as crypto from "crypto";
 
 describe("hms-solana", () => {
@@ -256,7 +256,7 @@ describe("hms-solana", () => {
 
     it("Allows authorized user to read record", async () => {
       const tx = await program.methods
-        .readRecord(new anchor.BN(1))
+        .readRecord(new anchor.BN(1), new anchor.BN(0), null)
         .accountsPartial({
           recordOwner: userKeypair.publicKey,
           accessor: doctorKeypair.publicKey,
@@ -286,6 +286,241 @@ describe("hms-solana", () => {
       expect(record.metadata).to.equal(doctorNote);
     });
 
+    it("Grants and revokes access for a credential NFT collection", async () => {
+      // Stand-in for a hospital's verified staff collection mint
+      const staffCollection = Keypair.generate().publicKey;
+      const [hmsCollection] = PublicKey.findProgramAddressSync(
+        [Buffer.from("hms_collection")],
+        program.programId,
+      );
+      const [collectionGrantPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("collection_grant"),
+          userKeypair.publicKey.toBuffer(),
+          staffCollection.toBuffer(),
+        ],
+        program.programId,
+      );
+
+      const expiresAt = new anchor.BN(Date.now() / 1000 + 86400);
+      await program.methods
+        .grantCollectionAccess(staffCollection, expiresAt, 1) // READ
+        .accountsPartial({
+          owner: userKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([userKeypair])
+        .rpc();
+
+      const collectionGrant = await program.account.collectionAccessGrant.fetch(collectionGrantPda);
+      expect(collectionGrant.owner.toString()).to.equal(userKeypair.publicKey.toString());
+      expect(collectionGrant.collection.toString()).to.equal(staffCollection.toString());
+      expect(collectionGrant.permissions).to.equal(1);
+
+      // A collection grant alone is not enough without a credential NFT
+      try {
+        await program.methods
+          .readRecord(new anchor.BN(1), new anchor.BN(0), null)
+          .accountsPartial({
+            recordOwner: userKeypair.publicKey,
+            accessor: researcherKeypair.publicKey,
+            accessGrant: null,
+            collectionGrant: collectionGrantPda,
+            credentialToken: null,
+            credentialMetadata: null,
+            hmsCollection,
+          })
+          .signers([researcherKeypair])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("InvalidCredentialToken");
+      }
+
+      await program.methods
+        .revokeCollectionAccess(staffCollection)
+        .accountsPartial({
+          owner: userKeypair.publicKey,
+        })
+        .signers([userKeypair])
+        .rpc();
+
+      try {
+        await program.account.collectionAccessGrant.fetch(collectionGrantPda);
+        expect.fail("Collection grant should have been closed");
+      } catch (error) {
+        expect(error.message).to.include("Account does not exist");
+      }
+
+      // Any profile holder can mint into the HMS collection, so it can't gate access
+      try {
+        await program.methods
+          .grantCollectionAccess(hmsCollection, expiresAt, 1) // READ
+          .accountsPartial({
+            owner: userKeypair.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([userKeypair])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("OpenCollectionNotAllowed");
+      }
+    });
+
+    it("Charges a grantee per read and per paid period", async () => {
+      const amount = new anchor.BN(anchor.web3.LAMPORTS_PER_SOL / 100);
+      const readAsDoctor = (maxPrice = amount) =>
+        program.methods
+          .readRecord(new anchor.BN(1), maxPrice, null)
+          .accountsPartial({
+            recordOwner: userKeypair.publicKey,
+            accessor: doctorKeypair.publicKey,
+          })
+          .signers([doctorKeypair])
+          .rpc();
+
+      await program.methods
+        .setAccessPrice(doctorKeypair.publicKey, {
+          amount,
+          mint: null,
+          period: new anchor.BN(0), // pay on every read
+        })
+        .accountsPartial({
+          owner: userKeypair.publicKey,
+        })
+        .signers([userKeypair])
+        .rpc();
+
+      // A payer's limit protects against the price being raised before the read lands
+      try {
+        await readAsDoctor(amount.subn(1));
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("AccessPriceAboveMax");
+      }
+
+      const ownerBalanceBefore = await provider.connection.getBalance(userKeypair.publicKey);
+      await readAsDoctor();
+      const ownerBalanceAfter = await provider.connection.getBalance(userKeypair.publicKey);
+      expect(ownerBalanceAfter - ownerBalanceBefore).to.equal(amount.toNumber());
+
+      // Switch to hourly pricing; reads are blocked until the period is paid
+      await program.methods
+        .setAccessPrice(doctorKeypair.publicKey, {
+          amount,
+          mint: null,
+          period: new anchor.BN(3600),
+        })
+        .accountsPartial({
+          owner: userKeypair.publicKey,
+        })
+        .signers([userKeypair])
+        .rpc();
+
+      try {
+        await readAsDoctor();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("AccessPaymentRequired");
+      }
+
+      // The payer's expected token has to match the price
+      try {
+        await program.methods
+          .payAccessPeriod(amount, Keypair.generate().publicKey)
+          .accountsPartial({
+            owner: userKeypair.publicKey,
+            grantee: doctorKeypair.publicKey,
+          })
+          .signers([doctorKeypair])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("AccessPriceMintMismatch");
+      }
+
+      await program.methods
+        .payAccessPeriod(amount, null)
+        .accountsPartial({
+          owner: userKeypair.publicKey,
+          grantee: doctorKeypair.publicKey,
+        })
+        .signers([doctorKeypair])
+        .rpc();
+
+      const accessGrant = await program.account.accessGrant.fetch(accessGrantPda);
+      expect(accessGrant.paidUntil.toNumber()).to.be.greaterThan(Date.now() / 1000);
+
+      await readAsDoctor();
+
+      // A period running past the grant's expiry is cut short and charged pro rata
+      await program.methods
+        .setAccessPrice(doctorKeypair.publicKey, {
+          amount,
+          mint: null,
+          period: new anchor.BN(2 * 86400),
+        })
+        .accountsPartial({
+          owner: userKeypair.publicKey,
+        })
+        .signers([userKeypair])
+        .rpc();
+
+      const balanceBeforeCapped = await provider.connection.getBalance(userKeypair.publicKey);
+      await program.methods
+        .payAccessPeriod(amount, null)
+        .accountsPartial({
+          owner: userKeypair.publicKey,
+          grantee: doctorKeypair.publicKey,
+        })
+        .signers([doctorKeypair])
+        .rpc();
+      const balanceAfterCapped = await provider.connection.getBalance(userKeypair.publicKey);
+
+      const cappedGrant = await program.account.accessGrant.fetch(accessGrantPda);
+      expect(cappedGrant.paidUntil.toNumber()).to.equal(cappedGrant.expiresAt.toNumber());
+      expect(balanceAfterCapped - balanceBeforeCapped).to.be.greaterThan(0);
+      expect(balanceAfterCapped - balanceBeforeCapped).to.be.lessThan(amount.toNumber());
+
+      // Nothing is left to pay for once access is paid up to the expiry
+      try {
+        await program.methods
+          .payAccessPeriod(amount.addn(1), null)
+          .accountsPartial({
+            owner: userKeypair.publicKey,
+            grantee: doctorKeypair.publicKey,
+          })
+          .signers([doctorKeypair])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("AccessPaidToGrantExpiry");
+      }
+
+      await program.methods
+        .setAccessPrice(doctorKeypair.publicKey, null)
+        .accountsPartial({
+          owner: userKeypair.publicKey,
+        })
+        .signers([userKeypair])
+        .rpc();
+      // Grants created with the current layout have nothing to migrate
+      try {
+        await program.methods
+          .migrateAccessGrant(doctorKeypair.publicKey)
+          .accountsPartial({
+            owner: userKeypair.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([userKeypair])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("AccessGrantUpToDate");
+      }
+    });
+
     it("Revokes access from user", async () => {
       const tx = await program.methods
         .revokeAccess(doctorKeypair.publicKey)
@@ -305,6 +540,182 @@ describe("hms-solana", () => {
         expect(error.message).to.include("Account does not exist");
       }
     });
+
+    it("Delegates record access through a transferable access ticket", async () => {
+      const ticketMint = Keypair.generate();
+      const [ticketPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("access_ticket"), ticketMint.publicKey.toBuffer()],
+        program.programId,
+      );
+      const [operatorApprovalPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("access_ticket"),
+          Buffer.from("operator"),
+          userKeypair.publicKey.toBuffer(),
+          doctorKeypair.publicKey.toBuffer(),
+        ],
+        program.programId,
+      );
+      const ticketAccountOf = (owner: PublicKey) =>
+        anchor.utils.token.associatedAddress({ mint: ticketMint.publicKey, owner });
+      const userTicketAccount = await ticketAccountOf(userKeypair.publicKey);
+      const researcherTicketAccount = await ticketAccountOf(researcherKeypair.publicKey);
+      const emergencyTicketAccount = await ticketAccountOf(emergencyContactKeypair.publicKey);
+      const ticketBalanceOf = async (tokenAccount: PublicKey) =>
+        (await provider.connection.getTokenAccountBalance(tokenAccount)).value.amount;
+      const readWithTicket = (ticketTokenAccount: PublicKey) =>
+        program.methods
+          .readRecord(new anchor.BN(1), new anchor.BN(0), null)
+          .accountsPartial({
+            recordOwner: userKeypair.publicKey,
+            accessor: researcherKeypair.publicKey,
+            accessGrant: null,
+            collectionGrant: null,
+            credentialToken: null,
+            credentialMetadata: null,
+            accessTicket: ticketPda,
+            ticketTokenAccount,
+          })
+          .signers([researcherKeypair])
+          .rpc();
+
+      const expiresAt = new anchor.BN(Date.now() / 1000 + 86400);
+      await program.methods
+        .mintAccessTicket(new anchor.BN(1), 1, expiresAt) // READ
+        .accountsPartial({
+          mint: ticketMint.publicKey,
+          holderTokenAccount: userTicketAccount,
+          recordOwner: userKeypair.publicKey,
+        })
+        .signers([userKeypair, ticketMint])
+        .rpc();
+
+      const ticket = await program.account.accessTicket.fetch(ticketPda);
+      expect(ticket.recordId.toNumber()).to.equal(1);
+
+      // The ticket is a single SPL token held in the patient's own wallet
+      const supply = await provider.connection.getTokenSupply(ticketMint.publicKey);
+      expect(supply.value.amount).to.equal("1");
+      expect(await ticketBalanceOf(userTicketAccount)).to.equal("1");
+
+      // The researcher cannot use a ticket they do not hold
+      try {
+        await readWithTicket(userTicketAccount);
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("NotTicketHolder");
+      }
+
+      // The doctor, as the patient's operator, hands the ticket to the researcher
+      await program.methods
+        .approveTicketOperator(doctorKeypair.publicKey)
+        .accountsPartial({
+          holder: userKeypair.publicKey,
+        })
+        .signers([userKeypair])
+        .rpc();
+
+      await program.methods
+        .transferAccessTicket(ticketMint.publicKey)
+        .accountsPartial({
+          ticketMint: ticketMint.publicKey,
+          fromTokenAccount: userTicketAccount,
+          recipient: researcherKeypair.publicKey,
+          toTokenAccount: researcherTicketAccount,
+          operatorApproval: operatorApprovalPda,
+          caller: doctorKeypair.publicKey,
+        })
+        .signers([doctorKeypair])
+        .rpc();
+
+      expect(await ticketBalanceOf(userTicketAccount)).to.equal("0");
+      expect(await ticketBalanceOf(researcherTicketAccount)).to.equal("1");
+
+      await readWithTicket(researcherTicketAccount);
+
+      // The doctor is not an operator of the new holder
+      try {
+        await program.methods
+          .transferAccessTicket(ticketMint.publicKey)
+          .accountsPartial({
+            ticketMint: ticketMint.publicKey,
+            fromTokenAccount: researcherTicketAccount,
+            recipient: doctorKeypair.publicKey,
+            toTokenAccount: await ticketAccountOf(doctorKeypair.publicKey),
+            operatorApproval: null,
+            caller: doctorKeypair.publicKey,
+          })
+          .signers([doctorKeypair])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("NotApprovedForTicket");
+      }
+
+      // An approved address may transfer the ticket once
+      await program.methods
+        .approveAccessTicket(ticketMint.publicKey)
+        .accountsPartial({
+          holderTokenAccount: researcherTicketAccount,
+          approved: emergencyContactKeypair.publicKey,
+          holder: researcherKeypair.publicKey,
+        })
+        .signers([researcherKeypair])
+        .rpc();
+
+      await program.methods
+        .transferAccessTicket(ticketMint.publicKey)
+        .accountsPartial({
+          ticketMint: ticketMint.publicKey,
+          fromTokenAccount: researcherTicketAccount,
+          recipient: emergencyContactKeypair.publicKey,
+          toTokenAccount: emergencyTicketAccount,
+          operatorApproval: null,
+          caller: emergencyContactKeypair.publicKey,
+        })
+        .signers([emergencyContactKeypair])
+        .rpc();
+
+      expect(await ticketBalanceOf(emergencyTicketAccount)).to.equal("1");
+
+      // The previous holder's reads now fail
+      try {
+        await readWithTicket(researcherTicketAccount);
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("NotTicketHolder");
+      }
+
+      await program.methods
+        .revokeTicketOperator(doctorKeypair.publicKey)
+        .accountsPartial({
+          holder: userKeypair.publicKey,
+        })
+        .signers([userKeypair])
+        .rpc();
+
+      // The patient can withdraw the ticket from whoever holds it
+      await program.methods
+        .revokeAccessTicket(ticketMint.publicKey)
+        .accountsPartial({
+          ticketMint: ticketMint.publicKey,
+          holderTokenAccount: emergencyTicketAccount,
+          recordOwner: userKeypair.publicKey,
+        })
+        .signers([userKeypair])
+        .rpc();
+
+      try {
+        await program.account.accessTicket.fetch(ticketPda);
+        expect.fail("Access ticket should have been closed");
+      } catch (error) {
+        expect(error.message).to.include("Account does not exist");
+      }
+
+      // The holder never delegated it back to the program, so it is frozen in place
+      const holderInfo = await provider.connection.getAccountInfo(emergencyTicketAccount);
+      expect(holderInfo.data[108]).to.equal(2);
+    });
   });
 
   describe("ZK Proof Access", () => {
@@ -470,257 +881,293 @@ describe("hms-solana", () => {
   describe("DAO Governance", () => {
     let proposalPda: PublicKey;
     let votePda: PublicKey;
-    const proposalId = 1;
+    let consentPda: PublicKey;
+    let configPda: PublicKey;
+    let proposalCounterPda: PublicKey;
+    let proposalId: number;
     const researchTopic = "COVID-19 Long-term Effects Study";
+    const votingPeriod = 10; // seconds, short enough to exercise execution
+    const executionDelay = 2; // seconds between queueing and execution
+    const proposalDeposit = new anchor.BN(0.01 * anchor.web3.LAMPORTS_PER_SOL);
+    const researchScope = {
+      recordTypes: [recordType],
+      cohortCriteria: "Adults 18-65 with a documented COVID-19 infection",
+      recordStart: new anchor.BN(0),
+      recordEnd: new anchor.BN(0),
+      accessExpiresAt: new anchor.BN(
+        Math.floor(Date.now() / 1000) + 90 * 24 * 60 * 60,
+      ),
+    };
 
-    it("Creates a research proposal", async () => {
-      [proposalPda] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("dao_governance"),
-          Buffer.from("proposal"),
-          new anchor.BN(proposalId).toArrayLike(Buffer, "le", 8),
-        ],
+    it("Initializes the governance config", async () => {
+      [configPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("dao_governance"), Buffer.from("config")],
         program.programId,
       );
-
+      // The provider wallet deployed the program and is its upgrade authority
       const tx = await program.methods
-        .createResearchProposal(new anchor.BN(proposalId), researchTopic)
+        .initializeGovernanceConfig(
+          new anchor.BN(1),
+          5000,
+          new anchor.BN(votingPeriod),
+          new anchor.BN(executionDelay),
+          proposalDeposit,
+        )
         .accountsPartial({
-          researcher: researcherKeypair.publicKey,
+          programData,
+          authority: provider.publicKey,
           systemProgram: SystemProgram.programId,
         })
-        .signers([researcherKeypair])
         .rpc();
 
-      console.log("Create research proposal tx:", tx);
+      console.log("Initialize governance config tx:", tx);
 
-      // Verify proposal was created
-      const proposal = await program.account.researchProposal.fetch(
-        proposalPda,
-      );
-      expect(proposal.researcher.toString()).to.equal(
-        researcherKeypair.publicKey.toString(),
-      );
-      expect(proposal.researchTopic).to.equal(researchTopic);
-      expect(proposal.yesVotes.toNumber()).to.equal(0);
+      // Verify config was created
+      const config = await program.account.governanceConfig.fetch(configPda);
+      expect(config.quorumVotes.toNumber()).to.equal(1);
+      expect(config.approvalThresholdBps).to.equal(5000);
+      expect(config.votingPeriod.toNumber()).to.equal(votingPeriod);
+      expect(config.executionDelay.toNumber()).to.equal(executionDelay);
     });
 
-    it("Votes on research proposal", async () => {
-      [votePda] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("dao_governance"),
-          Buffer.from("vote"),
-          new anchor.BN(proposalId).toArrayLike(Buffer, "le", 8),
-          userKeypair.publicKey.toBuffer(),
+    const [programData] = PublicKey.findProgramAddressSync(
+      [program.programId.toBuffer()],
+      new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111"),
+    );
+
+    // Compensation is paid in a throwaway SPL mint created with raw instructions
+    const tokenProgram = anchor.utils.token.TOKEN_PROGRAM_ID;
+    const compensationMint = Keypair.generate();
+    let researcherTokenAccount: PublicKey;
+    let ownerTokenAccount: PublicKey;
+
+    const createTokenAccountIx = (owner: PublicKey, tokenAccount: PublicKey) =>
+      new TransactionInstruction({
+        programId: anchor.utils.token.ASSOCIATED_PROGRAM_ID,
+        keys: [
+          { pubkey: provider.publicKey, isSigner: true, isWritable: true },
+          { pubkey: tokenAccount, isSigner: false, isWritable: true },
+          { pubkey: owner, isSigner: false, isWritable: false },
+          { pubkey: compensationMint.publicKey, isSigner: false, isWritable: false },
+          { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+          { pubkey: tokenProgram, isSigner: false, isWritable: false },
         ],
-        program.programId,
+        data: Buffer.alloc(0),
+      });
+
+    const balanceOf = async (tokenAccount: PublicKey) =>
+      (await provider.connection.getTokenAccountBalance(tokenAccount)).value.amount;
+
+    const createCompensationMint = async () => {
+      researcherTokenAccount = await anchor.utils.token.associatedAddress({
+        mint: compensationMint.publicKey,
+        owner: researcherKeypair.publicKey,
+      });
+      ownerTokenAccount = await anchor.utils.token.associatedAddress({
+        mint: compensationMint.publicKey,
+        owner: userKeypair.publicKey,
+      });
+
+      await provider.sendAndConfirm(
+        new Transaction().add(
+          SystemProgram.createAccount({
+            fromPubkey: provider.publicKey,
+            newAccountPubkey: compensationMint.publicKey,
+            lamports: await provider.connection.getMinimumBalanceForRentExemption(82),
+            space: 82,
+            programId: tokenProgram,
+          }),
+          // InitializeMint2 (instruction 20) with no freeze authority
+          new TransactionInstruction({
+            programId: tokenProgram,
+            keys: [
+              { pubkey: compensationMint.publicKey, isSigner: false, isWritable: true },
+            ],
+            data: Buffer.concat([
+              Buffer.from([20, 0]),
+              provider.publicKey.toBuffer(),
+              Buffer.from([0]),
+            ]),
+          }),
+          createTokenAccountIx(researcherKeypair.publicKey, researcherTokenAccount),
+          createTokenAccountIx(userKeypair.publicKey, ownerTokenAccount),
+          // MintTo (instruction 7)
+          new TransactionInstruction({
+            programId: tokenProgram,
+            keys: [
+              { pubkey: compensationMint.publicKey, isSigner: false, isWritable: true },
+              { pubkey: researcherTokenAccount, isSigner: false, isWritable: true },
+              { pubkey: provider.publicKey, isSigner: true, isWritable: false },
+            ],
+            data: Buffer.concat([
+              Buffer.from([7]),
+              new anchor.BN(10_000).toArrayLike(Buffer, "le", 8),
+            ]),
+          }),
+        ),
+        [compensationMint],
       );
+    };
 
-      const tx = await program.methods
-        .voteOnResearchProposal(new anchor.BN(proposalId), true)
+    it("Credentials the researcher through a trusted attestor", async () => {
+      // The doctor acts as the institution's ethics board
+      await program.methods
+        .registerResearchAttestor(doctorKeypair.publicKey, "St. Mary's IRB")
         .accountsPartial({
-          voter: userKeypair.publicKey,
+          programData,
+          authority: provider.publicKey,
           systemProgram: SystemProgram.programId,
         })
-        .signers([userKeypair])
         .rpc();
 
-      console.log("Vote on proposal tx:", tx);
+      const irbApprovalHash = crypto.randomBytes(32);
+      const expiresAt = Math.floor(Date.now() / 1000) + 365 * 24 * 60 * 60;
 
-      // Verify vote was recorded
-      const vote = await program.account.researchVote.fetch(votePda);
-      expect(vote.voter.toString()).to.equal(userKeypair.publicKey.toString());
-      expect(vote.vote).to.be.true;
+      const tx = await program.methods
+        .issueResearcherCredential(
+          researcherKeypair.publicKey,
+          "St. Mary's Hospital",
+          Array.from(irbApprovalHash),
+          new anchor.BN(expiresAt),
+        )
+        .accountsPartial({
+          attestor: doctorKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([doctorKeypair])
+        .rpc();
 
-      // Verify proposal vote count increased
-      const proposal = await program.account.researchProposal.fetch(
-        proposalPda,
+      console.log("Issue researcher credential tx:", tx);
+
+      const [credentialPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("researcher_credential"),
+          researcherKeypair.publicKey.toBuffer(),
+        ],
+        program.programId,
       );
-      expect(proposal.yesVotes.toNumber()).to.equal(1);
+      const credential = await program.account.researcherCredential.fetch(
+        credentialPda,
+      );
+      expect(credential.attestor.toString()).to.equal(
+        doctorKeypair.publicKey.toString(),
+      );
+      expect(credential.isRevoked).to.be.false;
     });
-  });
 
-  describe("Wearable Integration", () => {
-    let wearableDevicePda: PublicKey;
-    let dataBatchPda: PublicKey;
-    const deviceId = "smartwatch-001";
-    const deviceType = "fitness_tracker";
-    const deviceKeypair = Keypair.generate();
-    const batchId = 1;
+    const nextProposalId = async (): Promise<number> => {
+      const counter = await program.account.proposalCounter.fetch(
+        proposalCounterPda,
+      );
+      return counter.nextProposalId.toNumber();
+    };
 
-    it("Registers a wearable device", async () => {
-      [wearableDevicePda] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("wearable_device"),
-          userKeypair.publicKey.toBuffer(),
-          Buffer.from(deviceId),
-        ],
+    it("Initializes the proposal counter", async () => {
+      [proposalCounterPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("dao_governance"), Buffer.from("proposal_counter")],
         program.programId,
       );
 
       const tx = await program.methods
-        .registerWearableDevice(deviceId, deviceType, deviceKeypair.publicKey)
+        .initializeProposalCounter()
         .accountsPartial({
-          owner: userKeypair.publicKey,
+          payer: researcherKeypair.publicKey,
           systemProgram: SystemProgram.programId,
         })
-        .signers([userKeypair])
+        .signers([researcherKeypair])
         .rpc();
 
-      console.log("Register wearable device tx:", tx);
+      console.log("Initialize proposal counter tx:", tx);
 
-      // Verify device was registered
-      const device = await program.account.wearableDevice.fetch(
-        wearableDevicePda,
-      );
-      expect(device.owner.toString()).to.equal(
-        userKeypair.publicKey.toString(),
-      );
-      expect(device.deviceId).to.equal(deviceId);
-      expect(device.deviceType).to.equal(deviceType);
-      expect(device.isActive).to.be.true;
+      expect(await nextProposalId()).to.equal(0);
     });
 
-    it("Ingests wearable data", async () => {
-      [dataBatchPda] = PublicKey.findProgramAddressSync(
+    it("Creates a research proposal", async () => {
+      proposalId = await nextProposalId();
+      [proposalPda] = PublicKey.findProgramAddressSync(
         [
-          Buffer.from("wearable_device"),
-          Buffer.from("batch"),
-          userKeypair.publicKey.toBuffer(),
-          Buffer.from(deviceId),
-          new anchor.BN(batchId).toArrayLike(Buffer, "le", 8),
+          Buffer.from("dao_governance"),
+          Buffer.from("proposal"),
+          new anchor.BN(proposalId).toArrayLike(Buffer, "le", 8),
         ],
         program.programId,
       );
 
-      const encryptedDataUri = "QmWearableDataBatch123";
-      const batchDataHash = crypto.randomBytes(32);
-      const dataPointCount = 100;
-      const startTimestamp = Math.floor(Date.now() / 1000) - 3600; // 1 hour ago
-      const endTimestamp = Math.floor(Date.now() / 1000);
-
       const tx = await program.methods
-        .ingestWearableData(
-          deviceId,
-          new anchor.BN(batchId),
-          encryptedDataUri,
-          Array.from(batchDataHash),
-          dataPointCount,
-          new anchor.BN(startTimestamp),
-          new anchor.BN(endTimestamp),
-        )
+        .createResearchProposal(researchTopic, researchScope)
         .accountsPartial({
-          owner: userKeypair.publicKey,
+          researcher: researcherKeypair.publicKey,
           systemProgram: SystemProgram.programId,
         })
-        .signers([userKeypair])
+        .signers([researcherKeypair])
         .rpc();
 
-      console.log("Ingest wearable data tx:", tx);
+      console.log("Create research proposal tx:", tx);
 
-      // Verify data batch was created
-      const dataBatch = await program.account.wearableDataBatch.fetch(
-        dataBatchPda,
+      // Verify proposal was created
+      const proposal = await program.account.researchProposal.fetch(
+        proposalPda,
       );
-      expect(dataBatch.owner.toString()).to.equal(
-        userKeypair.publicKey.toString(),
+      expect(proposal.researcher.toString()).to.equal(
+        researcherKeypair.publicKey.toString(),
       );
-      expect(dataBatch.deviceId).to.equal(deviceId);
-      expect(dataBatch.dataPointCount).to.equal(dataPointCount);
-      expect(dataBatch.isProcessed).to.be.false;
-    });
-
-    it("Processes wearable data to health record", async () => {
-      const newRecordType = "heart_rate_data";
-      const newMetadata = "Heart rate data from smartwatch";
-
-      // Get current record count for PDA derivation
-      const profile = await program.account.userHealthProfile.fetch(
-        userProfilePda,
+      expect(proposal.proposalId.toNumber()).to.equal(proposalId);
+      expect(proposal.researchTopic).to.equal(researchTopic);
+      expect(proposal.scope.recordTypes).to.deep.equal([recordType]);
+      expect(proposal.scope.accessExpiresAt.toNumber()).to.equal(
+        researchScope.accessExpiresAt.toNumber(),
       );
-      const recordCount = profile.recordCount.toNumber();
+      expect(proposal.yesVotes.toNumber()).to.equal(0);
+      expect(await nextProposalId()).to.equal(proposalId + 1);
+    });
 
-      const [newRecordPda] = PublicKey.findProgramAddressSync(
+    it("Votes on research proposal", async () => {
+      [votePda] = PublicKey.findProgramAddressSync(
         [
-          Buffer.from("health_record"),
+          Buffer.from("dao_governance"),
+          Buffer.from("vote"),
+          new anchor.BN(proposalId).toArrayLike(Buffer, "le", 8),
           userKeypair.publicKey.toBuffer(),
-          new anchor.BN(recordCount).toArrayLike(Buffer, "le", 8),
         ],
         program.programId,
       );
 
       const tx = await program.methods
-        .processWearableDataToRecord(
-          deviceId,
-          new anchor.BN(batchId),
-          newRecordType,
-          newMetadata,
-        )
+        .voteOnResearchProposal(new anchor.BN(proposalId), true)
         .accountsPartial({
-          owner: userKeypair.publicKey,
+          voter: userKeypair.publicKey,
           systemProgram: SystemProgram.programId,
         })
         .signers([userKeypair])
         .rpc();
 
-      console.log("Process wearable data to record tx:", tx);
-
-      // Verify health record was created
-      const healthRecord = await program.account.healthRecord.fetch(
-        newRecordPda,
-      );
-      expect(healthRecord.owner.toString()).to.equal(
-        userKeypair.publicKey.toString(),
-      );
-      expect(healthRecord.recordType).to.equal(newRecordType);
-      expect(healthRecord.metadata).to.equal(newMetadata);
-
-      // Verify data batch was marked as processed
-      const dataBatch = await program.account.wearableDataBatch.fetch(
-        dataBatchPda,
-      );
-      expect(dataBatch.isProcessed).to.be.true;
-    });
-
-    it("Deactivates wearable device", async () => {
-      const tx = await program.methods
-        .deactivateWearableDevice(deviceId)
-        .accountsPartial({
-          owner: userKeypair.publicKey,
-        })
-        .signers([userKeypair])
-        .rpc();
+      console.log("Vote on proposal tx:", tx);
 
-      console.log("Deactivate wearable device tx:", tx);
+      // Verify vote was recorded
+      const vote = await program.account.researchVote.fetch(votePda);
+      expect(vote.voter.toString()).to.equal(userKeypair.publicKey.toString());
+      expect(vote.vote).to.be.true;
 
-      // Verify device was deactivated
-      const device = await program.account.wearableDevice.fetch(
-        wearableDevicePda,
+      // Verify proposal vote count increased
+      const proposal = await program.account.researchProposal.fetch(
+        proposalPda,
       );
-      expect(device.isActive).to.be.false;
+      expect(proposal.yesVotes.toNumber()).to.equal(1);
     });
-  });
-
-  describe("Cross-Device Sync", () => {
-    let syncStatePda1: PublicKey;
-    let syncStatePda2: PublicKey;
-    let syncOperationPda: PublicKey;
-    const device1Id = "mobile-app-001";
-    const device2Id = "web-app-001";
-    const syncKey = crypto.randomBytes(32);
 
-    it("Initializes sync state for device 1", async () => {
-      [syncStatePda1] = PublicKey.findProgramAddressSync(
+    it("Grants research consent", async () => {
+      [consentPda] = PublicKey.findProgramAddressSync(
         [
-          Buffer.from("sync_state"),
+          Buffer.from("dao_governance"),
+          Buffer.from("consent"),
+          new anchor.BN(proposalId).toArrayLike(Buffer, "le", 8),
           userKeypair.publicKey.toBuffer(),
-          Buffer.from(device1Id),
         ],
         program.programId,
       );
 
       const tx = await program.methods
-        .initializeSyncState(device1Id, Array.from(syncKey), true)
+        .grantResearchConsent(new anchor.BN(proposalId), [recordType])
         .accountsPartial({
           owner: userKeypair.publicKey,
           systemProgram: SystemProgram.programId,
@@ -728,191 +1175,2431 @@ describe("hms-solana", () => {
         .signers([userKeypair])
         .rpc();
 
-      console.log("Initialize sync state 1 tx:", tx);
+      console.log("Grant research consent tx:", tx);
 
-      // Verify sync state was created
-      const syncState = await program.account.syncState.fetch(syncStatePda1);
-      expect(syncState.owner.toString()).to.equal(
+      // Verify consent was recorded
+      const consent = await program.account.researchConsent.fetch(consentPda);
+      expect(consent.owner.toString()).to.equal(
         userKeypair.publicKey.toString(),
       );
-      expect(syncState.deviceId).to.equal(device1Id);
-      expect(syncState.isPrimary).to.be.true;
+      expect(consent.recordTypes).to.deep.equal([recordType]);
+      expect(consent.isWithdrawn).to.be.false;
     });
 
-    it("Initializes sync state for device 2", async () => {
-      [syncStatePda2] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("sync_state"),
-          userKeypair.publicKey.toBuffer(),
-          Buffer.from(device2Id),
-        ],
-        program.programId,
-      );
-
+    it("Withdraws research consent", async () => {
       const tx = await program.methods
-        .initializeSyncState(device2Id, Array.from(syncKey), false)
+        .withdrawResearchConsent(new anchor.BN(proposalId))
         .accountsPartial({
           owner: userKeypair.publicKey,
-          systemProgram: SystemProgram.programId,
         })
         .signers([userKeypair])
         .rpc();
 
-      console.log("Initialize sync state 2 tx:", tx);
+      console.log("Withdraw research consent tx:", tx);
 
-      // Verify sync state was created
-      const syncState = await program.account.syncState.fetch(syncStatePda2);
-      expect(syncState.owner.toString()).to.equal(
-        userKeypair.publicKey.toString(),
-      );
-      expect(syncState.deviceId).to.equal(device2Id);
-      expect(syncState.isPrimary).to.be.false;
+      // Verify consent was withdrawn
+      const consent = await program.account.researchConsent.fetch(consentPda);
+      expect(consent.isWithdrawn).to.be.true;
     });
 
-    it("Starts sync operation", async () => {
-      [syncOperationPda] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("sync_state"),
-          Buffer.from("operation"),
-          userKeypair.publicKey.toBuffer(),
-          Buffer.from(device1Id),
-          Buffer.from(device2Id),
-        ],
-        program.programId,
-      );
+    it("Grants research consent again after withdrawing it", async () => {
+      const grantConsent = (recordTypes: string[]) =>
+        program.methods
+          .grantResearchConsent(new anchor.BN(proposalId), recordTypes)
+          .accountsPartial({
+            owner: userKeypair.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([userKeypair])
+          .rpc();
 
-      const operationType = "full_sync";
+      await grantConsent([recordType]);
+      let consent = await program.account.researchConsent.fetch(consentPda);
+      expect(consent.isWithdrawn).to.be.false;
+      expect(consent.withdrawnAt.toNumber()).to.equal(0);
 
-      const tx = await program.methods
-        .startSyncOperation(
-          device1Id,
-          device2Id,
-          operationType,
-          Array.from(syncKey),
-        )
+      // Active consent cannot be granted twice
+      try {
+        await grantConsent([]);
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("ResearchConsentActive");
+      }
+
+      await program.methods
+        .withdrawResearchConsent(new anchor.BN(proposalId))
         .accountsPartial({
           owner: userKeypair.publicKey,
-          systemProgram: SystemProgram.programId,
         })
         .signers([userKeypair])
         .rpc();
+      consent = await program.account.researchConsent.fetch(consentPda);
+      expect(consent.isWithdrawn).to.be.true;
+    });
 
-      console.log("Start sync operation tx:", tx);
-
-      // Verify sync operation was created
-      const syncOperation = await program.account.syncOperation.fetch(
-        syncOperationPda,
+    it("Changes a research vote", async () => {
+      await program.methods
+        .changeResearchVote(new anchor.BN(proposalId), userKeypair.publicKey, false)
+        .accountsPartial({
+          delegation: null,
+          caster: userKeypair.publicKey,
+        })
+        .signers([userKeypair])
+        .rpc();
+
+      let proposal = await program.account.researchProposal.fetch(proposalPda);
+      expect(proposal.yesVotes.toNumber()).to.equal(0);
+      expect(proposal.noVotes.toNumber()).to.equal(1);
+      expect(proposal.totalVoters.toNumber()).to.equal(1);
+
+      const tx = await program.methods
+        .changeResearchVote(new anchor.BN(proposalId), userKeypair.publicKey, true)
+        .accountsPartial({
+          delegation: null,
+          caster: userKeypair.publicKey,
+        })
+        .signers([userKeypair])
+        .rpc();
+
+      console.log("Change research vote tx:", tx);
+
+      proposal = await program.account.researchProposal.fetch(proposalPda);
+      expect(proposal.yesVotes.toNumber()).to.equal(1);
+      expect(proposal.noVotes.toNumber()).to.equal(0);
+    });
+
+    it("Executes a research proposal only after its timelock", async () => {
+      try {
+        await program.methods
+          .executeResearchProposal(new anchor.BN(proposalId))
+          .accountsPartial({ researcher: researcherKeypair.publicKey })
+          .signers([researcherKeypair])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("ResearchProposalNotQueued");
+      }
+
+      // A quorate supermajority lets anyone queue before voting closes
+      const tx = await program.methods
+        .queueResearchProposal(new anchor.BN(proposalId))
+        .accountsPartial({ queuer: userKeypair.publicKey })
+        .signers([userKeypair])
+        .rpc();
+
+      console.log("Queue research proposal tx:", tx);
+
+      let proposal = await program.account.researchProposal.fetch(proposalPda);
+      expect(proposal.queuedAt.toNumber()).to.be.greaterThan(0);
+      expect(proposal.isApproved).to.be.false;
+
+      try {
+        await program.methods
+          .executeResearchProposal(new anchor.BN(proposalId))
+          .accountsPartial({ researcher: researcherKeypair.publicKey })
+          .signers([researcherKeypair])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("ExecutionTimelockActive");
+      }
+
+      await new Promise((resolve) =>
+        setTimeout(resolve, (executionDelay + 1) * 1000),
       );
-      expect(syncOperation.owner.toString()).to.equal(
-        userKeypair.publicKey.toString(),
+
+      // Only the researcher who submitted the proposal can execute it
+      try {
+        await program.methods
+          .executeResearchProposal(new anchor.BN(proposalId))
+          .accountsPartial({ researcher: userKeypair.publicKey })
+          .signers([userKeypair])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("UnauthorizedAccess");
+      }
+
+      await program.methods
+        .executeResearchProposal(new anchor.BN(proposalId))
+        .accountsPartial({ researcher: researcherKeypair.publicKey })
+        .signers([researcherKeypair])
+        .rpc();
+
+      proposal = await program.account.researchProposal.fetch(proposalPda);
+      expect(proposal.isExecuted).to.be.true;
+      expect(proposal.isApproved).to.be.true;
+
+      // Approved proposals get their deposit back
+      const balanceBefore = await provider.connection.getBalance(
+        researcherKeypair.publicKey,
+      );
+      await program.methods
+        .settleProposalDeposit(new anchor.BN(proposalId))
+        .accountsPartial({ researcher: researcherKeypair.publicKey })
+        .rpc();
+      const balanceAfter = await provider.connection.getBalance(
+        researcherKeypair.publicKey,
+      );
+      expect(balanceAfter - balanceBefore).to.be.greaterThan(
+        proposalDeposit.toNumber(),
       );
-      expect(syncOperation.sourceDevice).to.equal(device1Id);
-      expect(syncOperation.targetDevice).to.equal(device2Id);
-      expect(syncOperation.operationType).to.equal(operationType);
     });
 
-    it("Completes sync operation", async () => {
-      const recordsSynced = 5;
-      const newStateHash = crypto.randomBytes(32);
+    it("Compensates data owners once per distinct record", async () => {
+      await createCompensationMint();
+
+      const compensatedProposalId = await nextProposalId();
+      const proposalIdBn = new anchor.BN(compensatedProposalId);
+      const [compensationPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("dao_governance"),
+          Buffer.from("compensation"),
+          proposalIdBn.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId,
+      );
+      const [consentForProposalPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("dao_governance"),
+          Buffer.from("consent"),
+          proposalIdBn.toArrayLike(Buffer, "le", 8),
+          userKeypair.publicKey.toBuffer(),
+        ],
+        program.programId,
+      );
+
+      await program.methods
+        .createResearchProposal(researchTopic, { ...researchScope, recordTypes: [] })
+        .accountsPartial({
+          researcher: researcherKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([researcherKeypair])
+        .rpc();
 
+      // 1000 tokens escrowed: 100 per distinct record plus 50 per participant
       const tx = await program.methods
-        .completeSyncOperation(
-          device1Id,
-          device2Id,
-          new anchor.BN(recordsSynced),
-          Array.from(newStateHash),
+        .fundResearchCompensation(
+          proposalIdBn,
+          new anchor.BN(1000),
+          new anchor.BN(100),
+          new anchor.BN(50),
         )
+        .accountsPartial({
+          mint: compensationMint.publicKey,
+          researcherTokenAccount,
+          researcher: researcherKeypair.publicKey,
+          tokenProgram,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([researcherKeypair])
+        .rpc();
+
+      console.log("Fund research compensation tx:", tx);
+
+      await program.methods
+        .voteOnResearchProposal(proposalIdBn, true)
+        .accountsPartial({
+          voter: userKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([userKeypair])
+        .rpc();
+      await program.methods
+        .queueResearchProposal(proposalIdBn)
+        .accountsPartial({ queuer: userKeypair.publicKey })
+        .signers([userKeypair])
+        .rpc();
+      await new Promise((resolve) =>
+        setTimeout(resolve, (executionDelay + 1) * 1000),
+      );
+      await program.methods
+        .executeResearchProposal(proposalIdBn)
+        .accountsPartial({ researcher: researcherKeypair.publicKey })
+        .signers([researcherKeypair])
+        .rpc();
+
+      await program.methods
+        .grantResearchConsent(proposalIdBn, [])
         .accountsPartial({
           owner: userKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
         })
         .signers([userKeypair])
         .rpc();
 
-      console.log("Complete sync operation tx:", tx);
+      const accessLabResult = () =>
+        program.methods
+          .accessWithResearchGrant(proposalIdBn, new anchor.BN(1))
+          .accountsPartial({
+            recordOwner: userKeypair.publicKey,
+            researcher: researcherKeypair.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([researcherKeypair])
+          .rpc();
+      const claim = () =>
+        program.methods
+          .claimResearchCompensation(proposalIdBn)
+          .accountsPartial({
+            ownerTokenAccount,
+            owner: userKeypair.publicKey,
+            tokenProgram,
+          })
+          .signers([userKeypair])
+          .rpc();
 
-      // Verify sync operation was completed
-      const syncOperation = await program.account.syncOperation.fetch(
-        syncOperationPda,
+      await accessLabResult();
+      let consent = await program.account.researchConsent.fetch(consentForProposalPda);
+      expect(consent.recordsAccessed.toNumber()).to.equal(1);
+
+      await claim();
+      expect(await balanceOf(ownerTokenAccount)).to.equal("150");
+
+      // Reading the same record again is not paid for a second time
+      await accessLabResult();
+      consent = await program.account.researchConsent.fetch(consentForProposalPda);
+      expect(consent.recordsAccessed.toNumber()).to.equal(1);
+
+      try {
+        await claim();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("NothingToClaim");
+      }
+
+      const compensation = await program.account.researchCompensation.fetch(
+        compensationPda,
       );
-      expect(syncOperation.isSuccessful).to.be.true;
-      expect(syncOperation.recordsSynced.toNumber()).to.equal(recordsSynced);
+      expect(compensation.totalPaid.toNumber()).to.equal(150);
 
-      // Verify sync states were updated
-      const syncState1 = await program.account.syncState.fetch(syncStatePda1);
-      const syncState2 = await program.account.syncState.fetch(syncStatePda2);
-      expect(syncState1.syncCount.toNumber()).to.equal(1);
-      expect(syncState2.syncCount.toNumber()).to.equal(1);
+      // The remainder stays escrowed until the claim window closes
+      try {
+        await program.methods
+          .refundResearchCompensation(proposalIdBn)
+          .accountsPartial({
+            researcherTokenAccount,
+            researcher: researcherKeypair.publicKey,
+            tokenProgram,
+          })
+          .signers([researcherKeypair])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("CompensationClaimWindowOpen");
+      }
     });
 
-    it("Updates sync primary status", async () => {
-      const tx = await program.methods
-        .updateSyncPrimary(device2Id, true)
+    it("Lets a delegate vote until the delegator votes directly", async () => {
+      const delegatedProposalId = await nextProposalId();
+      const proposalIdBuffer = new anchor.BN(delegatedProposalId).toArrayLike(
+        Buffer,
+        "le",
+        8,
+      );
+      const [delegatedVotePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("dao_governance"),
+          Buffer.from("vote"),
+          proposalIdBuffer,
+          userKeypair.publicKey.toBuffer(),
+        ],
+        program.programId,
+      );
+
+      await program.methods
+        .delegateResearchVotes(emergencyContactKeypair.publicKey)
         .accountsPartial({
-          owner: userKeypair.publicKey,
+          delegator: userKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
         })
         .signers([userKeypair])
         .rpc();
 
-      console.log("Update sync primary tx:", tx);
+      await program.methods
+        .createResearchProposal(researchTopic, researchScope)
+        .accountsPartial({
+          researcher: researcherKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([researcherKeypair])
+        .rpc();
 
-      // Verify primary status was updated
-      const syncState = await program.account.syncState.fetch(syncStatePda2);
-      expect(syncState.isPrimary).to.be.true;
-    });
-  });
+      const tx = await program.methods
+        .castDelegatedResearchVote(
+          new anchor.BN(delegatedProposalId),
+          userKeypair.publicKey,
+          true,
+        )
+        .accountsPartial({
+          delegate: emergencyContactKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([emergencyContactKeypair])
+        .rpc();
 
-  describe("HMS NFT", () => {
-    it("Creates an HMS NFT", async () => {
-      const nftName = "HMS Profile NFT";
-      const nftSymbol = "HMSP";
-      const nftUri = "https://example.com/hms-nft.json";
+      console.log("Cast delegated research vote tx:", tx);
 
-      const mint = Keypair.generate();
-      const tokenAccount = await anchor.utils.token.associatedAddress({
-        mint: mint.publicKey,
-        owner: userKeypair.publicKey
-      });
+      let vote = await program.account.researchVote.fetch(delegatedVotePda);
+      expect(vote.voter.toString()).to.equal(userKeypair.publicKey.toString());
+      expect(vote.castBy.toString()).to.equal(
+        emergencyContactKeypair.publicKey.toString(),
+      );
 
-      const metadataProgramId = new PublicKey("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
-      const [metadataAccount, _] = PublicKey.findProgramAddressSync(
-          [
-              Buffer.from("metadata"),
-              metadataProgramId.toBuffer(),
-              mint.publicKey.toBuffer(),
-          ],
-          metadataProgramId
+      // The delegator overrides the delegate by voting directly
+      await program.methods
+        .changeResearchVote(
+          new anchor.BN(delegatedProposalId),
+          userKeypair.publicKey,
+          false,
+        )
+        .accountsPartial({
+          delegation: null,
+          caster: userKeypair.publicKey,
+        })
+        .signers([userKeypair])
+        .rpc();
+
+      vote = await program.account.researchVote.fetch(delegatedVotePda);
+      expect(vote.vote).to.be.false;
+      expect(vote.castBy.toString()).to.equal(userKeypair.publicKey.toString());
+      // The delegate paid for the vote account and stays its rent payer
+      expect(vote.rentPayer.toString()).to.equal(
+        emergencyContactKeypair.publicKey.toString(),
       );
-      const [masterEditionAccount, __] = PublicKey.findProgramAddressSync(
-          [
-              Buffer.from("metadata"),
-              metadataProgramId.toBuffer(),
-              mint.publicKey.toBuffer(),
-              Buffer.from("edition"),
-          ],
-          metadataProgramId
+
+      // The delegate can no longer change the vote
+      try {
+        await program.methods
+          .changeResearchVote(
+            new anchor.BN(delegatedProposalId),
+            userKeypair.publicKey,
+            true,
+          )
+          .accountsPartial({
+            delegation: PublicKey.findProgramAddressSync(
+              [
+                Buffer.from("dao_governance"),
+                Buffer.from("delegation"),
+                userKeypair.publicKey.toBuffer(),
+              ],
+              program.programId,
+            )[0],
+            caster: emergencyContactKeypair.publicKey,
+          })
+          .signers([emergencyContactKeypair])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("UnauthorizedAccess");
+      }
+
+      await program.methods
+        .revokeResearchVoteDelegation()
+        .accountsPartial({ delegator: userKeypair.publicKey })
+        .signers([userKeypair])
+        .rpc();
+    });
+
+    it("Changes governance parameters through a parameter proposal", async () => {
+      const [parameterCounterPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("dao_governance"), Buffer.from("param_proposal_counter")],
+        program.programId,
+      );
+      const parameterProposalId = (
+        await program.account.proposalCounter.fetch(parameterCounterPda)
+      ).nextProposalId.toNumber();
+      const [parameterProposalPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("dao_governance"),
+          Buffer.from("param_proposal"),
+          new anchor.BN(parameterProposalId).toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId,
       );
 
-      const tx = await program.methods
-        .createHmsNft(nftName, nftSymbol, nftUri)
-        .accounts({
-          authority: userKeypair.publicKey,
-          mint: mint.publicKey,
-          tokenAccount,
-          metadataAccount,
-          masterEditionAccount,
-          tokenProgram: anchor.utils.token.TOKEN_PROGRAM_ID,
-          associatedTokenProgram: anchor.utils.token.ASSOCIATED_PROGRAM_ID,
-          tokenMetadataProgram: metadataProgramId,
+      await program.methods
+        .createParameterProposal(
+          new anchor.BN(2),
+          6000,
+          new anchor.BN(votingPeriod),
+          new anchor.BN(executionDelay),
+          proposalDeposit,
+        )
+        .accountsPartial({
+          proposer: userKeypair.publicKey,
           systemProgram: SystemProgram.programId,
-          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
         })
-        .signers([userKeypair, mint])
+        .signers([userKeypair])
         .rpc();
 
-      console.log("Create HMS NFT tx:", tx);
+      await program.methods
+        .voteOnParameterProposal(new anchor.BN(parameterProposalId), true)
+        .accountsPartial({
+          voter: userKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([userKeypair])
+        .rpc();
 
-      // You can add assertions here to verify the NFT was created correctly
-      // For example, fetch the mint and check its supply, or fetch the metadata account
+      // Execution is rejected while voting is still open
+      try {
+        await program.methods
+          .executeParameterProposal(new anchor.BN(parameterProposalId))
+          .accountsPartial({ executor: userKeypair.publicKey })
+          .signers([userKeypair])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("VotingPeriodActive");
+      }
+
+      await new Promise((resolve) => setTimeout(resolve, (votingPeriod + 2) * 1000));
+
+      const tx = await program.methods
+        .executeParameterProposal(new anchor.BN(parameterProposalId))
+        .accountsPartial({ executor: userKeypair.publicKey })
+        .signers([userKeypair])
+        .rpc();
+
+      console.log("Execute parameter proposal tx:", tx);
+
+      // Verify the config picked up the new parameters
+      const config = await program.account.governanceConfig.fetch(configPda);
+      expect(config.quorumVotes.toNumber()).to.equal(2);
+      expect(config.approvalThresholdBps).to.equal(6000);
+
+      const parameterProposal =
+        await program.account.parameterChangeProposal.fetch(parameterProposalPda);
+      expect(parameterProposal.proposalId.toNumber()).to.equal(parameterProposalId);
+      expect(parameterProposal.tally.isExecuted).to.be.true;
+
+      try {
+        await program.methods
+          .executeParameterProposal(new anchor.BN(parameterProposalId))
+          .accountsPartial({ executor: doctorKeypair.publicKey })
+          .signers([doctorKeypair])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("ProposalAlreadyExecuted");
+      }
+    });
+
+    it("Cancels and closes a research proposal", async () => {
+      const cancelledProposalId = await nextProposalId();
+      const [cancelledProposalPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("dao_governance"),
+          Buffer.from("proposal"),
+          new anchor.BN(cancelledProposalId).toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId,
+      );
+
+      await program.methods
+        .createResearchProposal(researchTopic, researchScope)
+        .accountsPartial({
+          researcher: researcherKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([researcherKeypair])
+        .rpc();
+
+      const tx = await program.methods
+        .cancelResearchProposal(new anchor.BN(cancelledProposalId))
+        .accountsPartial({ researcher: researcherKeypair.publicKey })
+        .signers([researcherKeypair])
+        .rpc();
+
+      console.log("Cancel research proposal tx:", tx);
+
+      const proposal = await program.account.researchProposal.fetch(
+        cancelledProposalPda,
+      );
+      expect(proposal.isCancelled).to.be.true;
+
+      // Cancelled proposals get their deposit back
+      const balanceBefore = await provider.connection.getBalance(
+        researcherKeypair.publicKey,
+      );
+      await program.methods
+        .settleProposalDeposit(new anchor.BN(cancelledProposalId))
+        .accountsPartial({ researcher: researcherKeypair.publicKey })
+        .rpc();
+      const balanceAfter = await provider.connection.getBalance(
+        researcherKeypair.publicKey,
+      );
+      expect(balanceAfter - balanceBefore).to.be.greaterThan(
+        proposalDeposit.toNumber(),
+      );
+
+      await program.methods
+        .closeResearchProposal(new anchor.BN(cancelledProposalId))
+        .accountsPartial({ researcher: researcherKeypair.publicKey })
+        .signers([researcherKeypair])
+        .rpc();
+
+      // Verify the proposal account was closed
+      const info = await provider.connection.getAccountInfo(cancelledProposalPda);
+      expect(info).to.be.null;
+    });
+
+    it("Finalizes a rejected proposal and closes its votes", async () => {
+      const rejectedProposalId = await nextProposalId();
+      const proposalIdBuffer = new anchor.BN(rejectedProposalId).toArrayLike(
+        Buffer,
+        "le",
+        8,
+      );
+      const [rejectedProposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("dao_governance"), Buffer.from("proposal"), proposalIdBuffer],
+        program.programId,
+      );
+      const [rejectedVotePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("dao_governance"),
+          Buffer.from("vote"),
+          proposalIdBuffer,
+          userKeypair.publicKey.toBuffer(),
+        ],
+        program.programId,
+      );
+
+      await program.methods
+        .createResearchProposal(researchTopic, researchScope)
+        .accountsPartial({
+          researcher: researcherKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([researcherKeypair])
+        .rpc();
+
+      await program.methods
+        .fundResearchCompensation(
+          new anchor.BN(rejectedProposalId),
+          new anchor.BN(1000),
+          new anchor.BN(100),
+          new anchor.BN(0),
+        )
+        .accountsPartial({
+          mint: compensationMint.publicKey,
+          researcherTokenAccount,
+          researcher: researcherKeypair.publicKey,
+          tokenProgram,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([researcherKeypair])
+        .rpc();
+      const fundedBalance = await balanceOf(researcherTokenAccount);
+
+      await program.methods
+        .voteOnResearchProposal(new anchor.BN(rejectedProposalId), false)
+        .accountsPartial({
+          voter: userKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([userKeypair])
+        .rpc();
+
+      // A second profile holder votes no so the rejection is quorate and unanimous
+      await program.methods
+        .initializeProfile()
+        .accountsPartial({
+          user: emergencyContactKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([emergencyContactKeypair])
+        .rpc();
+      await program.methods
+        .voteOnResearchProposal(new anchor.BN(rejectedProposalId), false)
+        .accountsPartial({
+          voter: emergencyContactKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([emergencyContactKeypair])
+        .rpc();
+
+      await new Promise((resolve) => setTimeout(resolve, (votingPeriod + 2) * 1000));
+
+      const tx = await program.methods
+        .finalizeResearchProposal(new anchor.BN(rejectedProposalId))
+        .accountsPartial({ finalizer: userKeypair.publicKey })
+        .signers([userKeypair])
+        .rpc();
+
+      console.log("Finalize research proposal tx:", tx);
+
+      const proposal = await program.account.researchProposal.fetch(
+        rejectedProposalPda,
+      );
+      expect(proposal.isRejected).to.be.true;
+      expect(proposal.isApproved).to.be.false;
+
+      // Two-thirds or more voting no slashes the deposit into the treasury
+      const [treasuryPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("dao_governance"), Buffer.from("treasury")],
+        program.programId,
+      );
+      const slashedBefore = (
+        await program.account.governanceTreasury.fetch(treasuryPda)
+      ).totalSlashed.toNumber();
+      const researcherBalanceBefore = await provider.connection.getBalance(
+        researcherKeypair.publicKey,
+      );
+      await program.methods
+        .settleProposalDeposit(new anchor.BN(rejectedProposalId))
+        .accountsPartial({ researcher: researcherKeypair.publicKey })
+        .rpc();
+      const researcherBalanceAfter = await provider.connection.getBalance(
+        researcherKeypair.publicKey,
+      );
+      const treasury = await program.account.governanceTreasury.fetch(treasuryPda);
+      expect(treasury.totalSlashed.toNumber() - slashedBefore).to.equal(
+        proposalDeposit.toNumber(),
+      );
+      // Only the deposit account's rent is returned
+      expect(researcherBalanceAfter - researcherBalanceBefore).to.be.lessThan(
+        proposalDeposit.toNumber(),
+      );
+
+      // Nothing is owed on a rejected proposal, so the escrow comes back at once
+      await program.methods
+        .refundResearchCompensation(new anchor.BN(rejectedProposalId))
+        .accountsPartial({
+          researcherTokenAccount,
+          researcher: researcherKeypair.publicKey,
+          tokenProgram,
+        })
+        .signers([researcherKeypair])
+        .rpc();
+      expect(Number(await balanceOf(researcherTokenAccount))).to.equal(
+        Number(fundedBalance) + 1000,
+      );
+
+      await program.methods
+        .closeResearchVote(new anchor.BN(rejectedProposalId))
+        .accountsPartial({
+          voter: userKeypair.publicKey,
+          rentPayer: userKeypair.publicKey,
+        })
+        .signers([userKeypair])
+        .rpc();
+
+      // Verify the vote account was closed
+      const info = await provider.connection.getAccountInfo(rejectedVotePda);
+      expect(info).to.be.null;
+    });
+
+    it("Pays out slashed deposits through a treasury withdrawal proposal", async () => {
+      const [treasuryPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("dao_governance"), Buffer.from("treasury")],
+        program.programId,
+      );
+      const [treasuryCounterPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("dao_governance"), Buffer.from("treasury_proposal_counter")],
+        program.programId,
+      );
+      const withdrawalProposalId = (
+        await program.account.proposalCounter.fetch(treasuryCounterPda)
+      ).nextProposalId.toNumber();
+      const withdrawalAmount = proposalDeposit;
+
+      await program.methods
+        .createTreasuryWithdrawalProposal(doctorKeypair.publicKey, withdrawalAmount)
+        .accountsPartial({
+          proposer: userKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([userKeypair])
+        .rpc();
+
+      // The config now requires two votes
+      for (const voter of [userKeypair, emergencyContactKeypair]) {
+        await program.methods
+          .voteOnTreasuryWithdrawalProposal(new anchor.BN(withdrawalProposalId), true)
+          .accountsPartial({
+            voter: voter.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([voter])
+          .rpc();
+      }
+
+      await new Promise((resolve) => setTimeout(resolve, (votingPeriod + 2) * 1000));
+
+      const withdrawnBefore = (
+        await program.account.governanceTreasury.fetch(treasuryPda)
+      ).totalWithdrawn.toNumber();
+      const recipientBalanceBefore = await provider.connection.getBalance(
+        doctorKeypair.publicKey,
+      );
+
+      const tx = await program.methods
+        .executeTreasuryWithdrawalProposal(new anchor.BN(withdrawalProposalId))
+        .accountsPartial({
+          recipient: doctorKeypair.publicKey,
+          executor: userKeypair.publicKey,
+        })
+        .signers([userKeypair])
+        .rpc();
+
+      console.log("Execute treasury withdrawal tx:", tx);
+
+      const recipientBalanceAfter = await provider.connection.getBalance(
+        doctorKeypair.publicKey,
+      );
+      expect(recipientBalanceAfter - recipientBalanceBefore).to.equal(
+        withdrawalAmount.toNumber(),
+      );
+      const treasury = await program.account.governanceTreasury.fetch(treasuryPda);
+      expect(treasury.totalWithdrawn.toNumber() - withdrawnBefore).to.equal(
+        withdrawalAmount.toNumber(),
+      );
+
+      // A withdrawal is only paid once
+      try {
+        await program.methods
+          .executeTreasuryWithdrawalProposal(new anchor.BN(withdrawalProposalId))
+          .accountsPartial({
+            recipient: doctorKeypair.publicKey,
+            executor: emergencyContactKeypair.publicKey,
+          })
+          .signers([emergencyContactKeypair])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("ProposalAlreadyExecuted");
+      }
+    });
+
+    it("Blocks proposals once the researcher credential is revoked", async () => {
+      const tx = await program.methods
+        .revokeResearcherCredential(researcherKeypair.publicKey)
+        .accountsPartial({ attestor: doctorKeypair.publicKey })
+        .signers([doctorKeypair])
+        .rpc();
+
+      console.log("Revoke researcher credential tx:", tx);
+
+      try {
+        await program.methods
+          .createResearchProposal(researchTopic, researchScope)
+          .accountsPartial({
+            researcher: researcherKeypair.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([researcherKeypair])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("InvalidResearcherCredential");
+      }
+    });
+
+    it("Renews a revoked researcher credential", async () => {
+      const expiresAt = Math.floor(Date.now() / 1000) + 365 * 24 * 60 * 60;
+
+      // Another attestor cannot undo the doctor's revocation
+      await program.methods
+        .registerResearchAttestor(provider.publicKey, "Regional IRB")
+        .accountsPartial({
+          programData,
+          authority: provider.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      try {
+        await program.methods
+          .renewResearcherCredential(
+            researcherKeypair.publicKey,
+            "Regional Hospital",
+            Array.from(crypto.randomBytes(32)),
+            new anchor.BN(expiresAt),
+          )
+          .accountsPartial({ attestor: provider.publicKey })
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("UnauthorizedAccess");
+      }
+
+      const tx = await program.methods
+        .renewResearcherCredential(
+          researcherKeypair.publicKey,
+          "St. Mary's Hospital",
+          Array.from(crypto.randomBytes(32)),
+          new anchor.BN(expiresAt),
+        )
+        .accountsPartial({ attestor: doctorKeypair.publicKey })
+        .signers([doctorKeypair])
+        .rpc();
+
+      console.log("Renew researcher credential tx:", tx);
+
+      const [credentialPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("researcher_credential"),
+          researcherKeypair.publicKey.toBuffer(),
+        ],
+        program.programId,
+      );
+      const credential = await program.account.researcherCredential.fetch(
+        credentialPda,
+      );
+      expect(credential.isRevoked).to.be.false;
+      expect(credential.expiresAt.toNumber()).to.equal(expiresAt);
+    });
+  });
+
+  describe("Wearable Integration", () => {
+    let wearableDevicePda: PublicKey;
+    let dataBatchPda: PublicKey;
+    const deviceId = "smartwatch-001";
+    const deviceType = "fitness_tracker";
+    const deviceKeypair = Keypair.generate();
+    const batchId = 1;
+
+    it("Registers a wearable device", async () => {
+      [wearableDevicePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("wearable_device"),
+          userKeypair.publicKey.toBuffer(),
+          Buffer.from(deviceId),
+        ],
+        program.programId,
+      );
+
+      const tx = await program.methods
+        .registerWearableDevice(deviceId, deviceType, deviceKeypair.publicKey)
+        .accountsPartial({
+          owner: userKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([userKeypair])
+        .rpc();
+
+      console.log("Register wearable device tx:", tx);
+
+      // Verify device was registered
+      const device = await program.account.wearableDevice.fetch(
+        wearableDevicePda,
+      );
+      expect(device.owner.toString()).to.equal(
+        userKeypair.publicKey.toString(),
+      );
+      expect(device.deviceId).to.equal(deviceId);
+      expect(device.deviceType).to.equal(deviceType);
+      expect(device.isActive).to.be.true;
+    });
+
+    it("Ingests wearable data", async () => {
+      [dataBatchPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("wearable_device"),
+          Buffer.from("batch"),
+          userKeypair.publicKey.toBuffer(),
+          Buffer.from(deviceId),
+          new anchor.BN(batchId).toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId,
+      );
+
+      const encryptedDataUri = "QmWearableDataBatch123";
+      const batchDataHash = crypto.randomBytes(32);
+      const dataPointCount = 100;
+      const startTimestamp = Math.floor(Date.now() / 1000) - 3600; // 1 hour ago
+      const endTimestamp = Math.floor(Date.now() / 1000);
+
+      const tx = await program.methods
+        .ingestWearableData(
+          deviceId,
+          new anchor.BN(batchId),
+          encryptedDataUri,
+          Array.from(batchDataHash),
+          dataPointCount,
+          new anchor.BN(startTimestamp),
+          new anchor.BN(endTimestamp),
+        )
+        .accountsPartial({
+          owner: userKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([userKeypair])
+        .rpc();
+
+      console.log("Ingest wearable data tx:", tx);
+
+      // Verify data batch was created
+      const dataBatch = await program.account.wearableDataBatch.fetch(
+        dataBatchPda,
+      );
+      expect(dataBatch.owner.toString()).to.equal(
+        userKeypair.publicKey.toString(),
+      );
+      expect(dataBatch.deviceId).to.equal(deviceId);
+      expect(dataBatch.dataPointCount).to.equal(dataPointCount);
+      expect(dataBatch.isProcessed).to.be.false;
+    });
+
+    it("Processes wearable data to health record", async () => {
+      const newRecordType = "heart_rate_data";
+      const newMetadata = "Heart rate data from smartwatch";
+
+      // Get current record count for PDA derivation
+      const profile = await program.account.userHealthProfile.fetch(
+        userProfilePda,
+      );
+      const recordCount = profile.recordCount.toNumber();
+
+      const [newRecordPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("health_record"),
+          userKeypair.publicKey.toBuffer(),
+          new anchor.BN(recordCount).toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId,
+      );
+
+      const tx = await program.methods
+        .processWearableDataToRecord(
+          deviceId,
+          new anchor.BN(batchId),
+          newRecordType,
+          newMetadata,
+        )
+        .accountsPartial({
+          owner: userKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([userKeypair])
+        .rpc();
+
+      console.log("Process wearable data to record tx:", tx);
+
+      // Verify health record was created
+      const healthRecord = await program.account.healthRecord.fetch(
+        newRecordPda,
+      );
+      expect(healthRecord.owner.toString()).to.equal(
+        userKeypair.publicKey.toString(),
+      );
+      expect(healthRecord.recordType).to.equal(newRecordType);
+      expect(healthRecord.metadata).to.equal(newMetadata);
+
+      // Verify data batch was marked as processed
+      const dataBatch = await program.account.wearableDataBatch.fetch(
+        dataBatchPda,
+      );
+      expect(dataBatch.isProcessed).to.be.true;
+    });
+
+    it("Deactivates wearable device", async () => {
+      const tx = await program.methods
+        .deactivateWearableDevice(deviceId)
+        .accountsPartial({
+          owner: userKeypair.publicKey,
+        })
+        .signers([userKeypair])
+        .rpc();
+
+      console.log("Deactivate wearable device tx:", tx);
+
+      // Verify device was deactivated
+      const device = await program.account.wearableDevice.fetch(
+        wearableDevicePda,
+      );
+      expect(device.isActive).to.be.false;
+    });
+  });
+
+  describe("Cross-Device Sync", () => {
+    let syncStatePda1: PublicKey;
+    let syncStatePda2: PublicKey;
+    let syncOperationPda: PublicKey;
+    const device1Id = "mobile-app-001";
+    const device2Id = "web-app-001";
+    // Devices share the raw key off-chain; only the head of a hash chain over
+    // it is stored, and each use reveals the link before the current head
+    const sha256 = (data: Buffer) => crypto.createHash("sha256").update(data).digest();
+    const hashChain = (syncKey: Buffer, length: number) => {
+      const links = [syncKey];
+      while (links.length < length) {
+        links.push(sha256(links[links.length - 1]));
+      }
+      return links;
+    };
+    let syncKeyChain = hashChain(crypto.randomBytes(32), 64);
+    const syncKeyCommitment = () => Array.from(syncKeyChain[syncKeyChain.length - 1]);
+
+    const [syncRegistryPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("sync_state"),
+        Buffer.from("registry"),
+        userKeypair.publicKey.toBuffer(),
+      ],
+      program.programId,
+    );
+
+    const findSyncOperationPda = (operationId: number) =>
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("sync_state"),
+          Buffer.from("operation"),
+          userKeypair.publicKey.toBuffer(),
+          Buffer.from(device1Id),
+          Buffer.from(device2Id),
+          new anchor.BN(operationId).toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId,
+      )[0];
+
+    const syncKeyProof = async () => {
+      const registry = await program.account.syncRegistry.fetch(syncRegistryPda);
+      const head = Buffer.from(registry.syncKeyCommitment);
+      const index = syncKeyChain.findIndex((link) => link.equals(head));
+      return Array.from(syncKeyChain[index - 1]);
+    };
+
+    it("Initializes the sync registry", async () => {
+      const tx = await program.methods
+        .initializeSyncRegistry(syncKeyCommitment())
+        .accountsPartial({
+          owner: userKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([userKeypair])
+        .rpc();
+
+      console.log("Initialize sync registry tx:", tx);
+
+      const registry = await program.account.syncRegistry.fetch(syncRegistryPda);
+      expect(registry.primaryDevice).to.equal("");
+      expect(registry.deviceCount.toNumber()).to.equal(0);
+    });
+
+    it("Initializes sync state for device 1", async () => {
+      [syncStatePda1] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("sync_state"),
+          userKeypair.publicKey.toBuffer(),
+          Buffer.from(device1Id),
+        ],
+        program.programId,
+      );
+
+      const tx = await program.methods
+        .initializeSyncState(device1Id, await syncKeyProof(), true)
+        .accountsPartial({
+          owner: userKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([userKeypair])
+        .rpc();
+
+      console.log("Initialize sync state 1 tx:", tx);
+
+      // Verify sync state was created
+      const syncState = await program.account.syncState.fetch(syncStatePda1);
+      expect(syncState.owner.toString()).to.equal(
+        userKeypair.publicKey.toString(),
+      );
+      expect(syncState.deviceId).to.equal(device1Id);
+      expect(syncState.isPrimary).to.be.true;
+    });
+
+    it("Initializes sync state for device 2", async () => {
+      [syncStatePda2] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("sync_state"),
+          userKeypair.publicKey.toBuffer(),
+          Buffer.from(device2Id),
+        ],
+        program.programId,
+      );
+
+      const tx = await program.methods
+        .initializeSyncState(device2Id, await syncKeyProof(), false)
+        .accountsPartial({
+          owner: userKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([userKeypair])
+        .rpc();
+
+      console.log("Initialize sync state 2 tx:", tx);
+
+      // Verify sync state was created
+      const syncState = await program.account.syncState.fetch(syncStatePda2);
+      expect(syncState.owner.toString()).to.equal(
+        userKeypair.publicKey.toString(),
+      );
+      expect(syncState.deviceId).to.equal(device2Id);
+      expect(syncState.isPrimary).to.be.false;
+    });
+
+    it("Starts sync operation", async () => {
+      syncOperationPda = findSyncOperationPda(0);
+
+      const operationType = "full_sync";
+      const proof = await syncKeyProof();
+
+      const tx = await program.methods
+        .startSyncOperation(device1Id, device2Id, operationType, proof)
+        .accountsPartial({
+          owner: userKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([userKeypair])
+        .rpc();
+
+      console.log("Start sync operation tx:", tx);
+
+      // Verify sync operation was created
+      const syncOperation = await program.account.syncOperation.fetch(
+        syncOperationPda,
+      );
+      expect(syncOperation.owner.toString()).to.equal(
+        userKeypair.publicKey.toString(),
+      );
+      expect(syncOperation.sourceDevice).to.equal(device1Id);
+      expect(syncOperation.targetDevice).to.equal(device2Id);
+      expect(syncOperation.operationType).to.equal(operationType);
+      expect(syncOperation.operationId.toNumber()).to.equal(0);
+
+      const syncState = await program.account.syncState.fetch(syncStatePda1);
+      expect(syncState.nextOperationId.toNumber()).to.equal(1);
+
+      // A revealed proof becomes the new head and cannot be replayed
+      const registry = await program.account.syncRegistry.fetch(syncRegistryPda);
+      expect(registry.syncKeyCommitment).to.deep.equal(proof);
+      try {
+        await program.methods
+          .startSyncOperation(device1Id, device2Id, "incremental", proof)
+          .accountsPartial({
+            owner: userKeypair.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([userKeypair])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("SyncKeyMismatch");
+      }
+    });
+
+    it("Completes sync operation", async () => {
+      const recordsSynced = 5;
+      const newStateHash = crypto.randomBytes(32);
+
+      const tx = await program.methods
+        .completeSyncOperation(
+          device1Id,
+          device2Id,
+          new anchor.BN(0),
+          new anchor.BN(recordsSynced),
+          Array.from(newStateHash),
+        )
+        .accountsPartial({
+          owner: userKeypair.publicKey,
+        })
+        .signers([userKeypair])
+        .rpc();
+
+      console.log("Complete sync operation tx:", tx);
+
+      // Verify sync operation was completed
+      const syncOperation = await program.account.syncOperation.fetch(
+        syncOperationPda,
+      );
+      expect(syncOperation.isSuccessful).to.be.true;
+      expect(syncOperation.recordsSynced.toNumber()).to.equal(recordsSynced);
+
+      // Verify sync states were updated
+      const syncState1 = await program.account.syncState.fetch(syncStatePda1);
+      const syncState2 = await program.account.syncState.fetch(syncStatePda2);
+      expect(syncState1.syncCount.toNumber()).to.equal(1);
+      expect(syncState2.syncCount.toNumber()).to.equal(1);
+    });
+
+    it("Syncs the same device pair again and closes finished operations", async () => {
+      await program.methods
+        .startSyncOperation(device1Id, device2Id, "incremental", await syncKeyProof())
+        .accountsPartial({
+          owner: userKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([userKeypair])
+        .rpc();
+
+      const secondOperationPda = findSyncOperationPda(1);
+      let syncOperation = await program.account.syncOperation.fetch(
+        secondOperationPda,
+      );
+      expect(syncOperation.operationId.toNumber()).to.equal(1);
+
+      // Pending operations cannot be closed
+      try {
+        await program.methods
+          .closeSyncOperation(device1Id, device2Id, new anchor.BN(1))
+          .accountsPartial({ owner: userKeypair.publicKey })
+          .signers([userKeypair])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("SyncOperationPending");
+      }
+
+      await program.methods
+        .failSyncOperation(device1Id, device2Id, new anchor.BN(1), "Target offline")
+        .accountsPartial({ owner: userKeypair.publicKey })
+        .signers([userKeypair])
+        .rpc();
+
+      syncOperation = await program.account.syncOperation.fetch(
+        secondOperationPda,
+      );
+      expect(syncOperation.isSuccessful).to.be.false;
+      expect(syncOperation.errorMessage).to.equal("Target offline");
+
+      const tx = await program.methods
+        .closeSyncOperation(device1Id, device2Id, new anchor.BN(0))
+        .accountsPartial({ owner: userKeypair.publicKey })
+        .signers([userKeypair])
+        .rpc();
+
+      console.log("Close sync operation tx:", tx);
+
+      const closed = await provider.connection.getAccountInfo(syncOperationPda);
+      expect(closed).to.be.null;
+    });
+
+    it("Rotates the sync key across both devices", async () => {
+      const oldProof = await syncKeyProof();
+      syncKeyChain = hashChain(crypto.randomBytes(32), 64);
+
+      // One rotation on the registry moves every device to the new key
+      const tx = await program.methods
+        .rotateSyncKey(syncKeyCommitment())
+        .accountsPartial({ owner: userKeypair.publicKey })
+        .signers([userKeypair])
+        .rpc();
+
+      console.log("Rotate sync key tx:", tx);
+
+      const registry = await program.account.syncRegistry.fetch(syncRegistryPda);
+      expect(registry.syncKeyCommitment).to.deep.equal(syncKeyCommitment());
+      expect(registry.keyEpoch.toNumber()).to.equal(1);
+
+      try {
+        await program.methods
+          .startSyncOperation(device1Id, device2Id, "incremental", oldProof)
+          .accountsPartial({
+            owner: userKeypair.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([userKeypair])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("SyncKeyMismatch");
+      }
+    });
+
+    it("Updates sync primary status", async () => {
+      // Promoting a device requires demoting the current primary
+      try {
+        await program.methods
+          .updateSyncPrimary(device2Id, true)
+          .accountsPartial({
+            previousPrimary: null,
+            owner: userKeypair.publicKey,
+          })
+          .signers([userKeypair])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("PrimaryDeviceExists");
+      }
+
+      const tx = await program.methods
+        .updateSyncPrimary(device2Id, true)
+        .accountsPartial({
+          previousPrimary: syncStatePda1,
+          owner: userKeypair.publicKey,
+        })
+        .signers([userKeypair])
+        .rpc();
+
+      console.log("Update sync primary tx:", tx);
+
+      // Verify primary status moved from device 1 to device 2
+      const syncState = await program.account.syncState.fetch(syncStatePda2);
+      expect(syncState.isPrimary).to.be.true;
+      const previousPrimary = await program.account.syncState.fetch(syncStatePda1);
+      expect(previousPrimary.isPrimary).to.be.false;
+      const registry = await program.account.syncRegistry.fetch(syncRegistryPda);
+      expect(registry.primaryDevice).to.equal(device2Id);
+      expect(registry.deviceCount.toNumber()).to.equal(2);
+    });
+
+    it("Distinguishes stale and concurrent updates with version vectors", async () => {
+      const startSync = async (source: string, target: string, operationType: string) =>
+        program.methods
+          .startSyncOperation(source, target, operationType, await syncKeyProof())
+          .accountsPartial({
+            owner: userKeypair.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([userKeypair])
+          .rpc();
+
+      await program.methods
+        .recordSyncUpdate(device1Id, Array.from(crypto.randomBytes(32)))
+        .accountsPartial({ owner: userKeypair.publicKey })
+        .signers([userKeypair])
+        .rpc();
+
+      // Device 2 has not seen device 1's update, so it cannot push to it
+      try {
+        await startSync(device2Id, device1Id, "incremental");
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("StaleSyncSource");
+      }
+
+      await program.methods
+        .recordSyncUpdate(device2Id, Array.from(crypto.randomBytes(32)))
+        .accountsPartial({ owner: userKeypair.publicKey })
+        .signers([userKeypair])
+        .rpc();
+
+      // Both devices now have unseen updates
+      try {
+        await startSync(device1Id, device2Id, "incremental");
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("SyncConflict");
+      }
+
+      // The primary (device 2) resolves the conflict
+      const tx = await startSync(device2Id, device1Id, "conflict_resolution");
+      console.log("Start conflict resolution tx:", tx);
+
+      await program.methods
+        .completeSyncOperation(
+          device2Id,
+          device1Id,
+          new anchor.BN(0),
+          new anchor.BN(2),
+          Array.from(crypto.randomBytes(32)),
+        )
+        .accountsPartial({ owner: userKeypair.publicKey })
+        .signers([userKeypair])
+        .rpc();
+
+      const syncState1 = await program.account.syncState.fetch(syncStatePda1);
+      const syncState2 = await program.account.syncState.fetch(syncStatePda2);
+      const registry = await program.account.syncRegistry.fetch(syncRegistryPda);
+      const vector1 = registry.versionVectors[syncState1.deviceSlot].map((v) => v.toNumber());
+      const vector2 = registry.versionVectors[syncState2.deviceSlot].map((v) => v.toNumber());
+      expect(vector1).to.deep.equal(vector2);
+      expect(vector2[syncState1.deviceSlot]).to.equal(1);
+      expect(vector2[syncState2.deviceSlot]).to.equal(2);
+    });
+
+    it("Revokes a lost device and forces a sync key rotation", async () => {
+      const device3Id = "tablet-001";
+      const [syncStatePda3] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("sync_state"),
+          userKeypair.publicKey.toBuffer(),
+          Buffer.from(device3Id),
+        ],
+        program.programId,
+      );
+
+      await program.methods
+        .initializeSyncState(device3Id, await syncKeyProof(), false)
+        .accountsPartial({
+          owner: userKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([userKeypair])
+        .rpc();
+
+      // An operation still in flight when the tablet goes missing
+      const pendingOperationId = (
+        await program.account.syncState.fetch(syncStatePda1)
+      ).nextOperationId;
+      await program.methods
+        .startSyncOperation(device1Id, device2Id, "incremental", await syncKeyProof())
+        .accountsPartial({
+          owner: userKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([userKeypair])
+        .rpc();
+
+      const tx = await program.methods
+        .revokeSyncDevice(device3Id)
+        .accountsPartial({ owner: userKeypair.publicKey })
+        .signers([userKeypair])
+        .rpc();
+
+      console.log("Revoke sync device tx:", tx);
+
+      expect(await provider.connection.getAccountInfo(syncStatePda3)).to.be.null;
+      const registry = await program.account.syncRegistry.fetch(syncRegistryPda);
+      expect(registry.deviceCount.toNumber()).to.equal(2);
+      expect(registry.keyRotationRequired).to.be.true;
+
+      try {
+        await program.methods
+          .completeSyncOperation(
+            device1Id,
+            device2Id,
+            pendingOperationId,
+            new anchor.BN(1),
+            Array.from(crypto.randomBytes(32)),
+          )
+          .accountsPartial({ owner: userKeypair.publicKey })
+          .signers([userKeypair])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("SyncOperationInvalidated");
+      }
+
+      // The remaining devices cannot sync until they move to a new key
+      try {
+        await program.methods
+          .startSyncOperation(device1Id, device2Id, "incremental", await syncKeyProof())
+          .accountsPartial({
+            owner: userKeypair.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([userKeypair])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("SyncKeyRotationRequired");
+      }
+
+      syncKeyChain = hashChain(crypto.randomBytes(32), 64);
+      await program.methods
+        .rotateSyncKey(syncKeyCommitment())
+        .accountsPartial({ owner: userKeypair.publicKey })
+        .signers([userKeypair])
+        .rpc();
+
+      const rotated = await program.account.syncRegistry.fetch(syncRegistryPda);
+      expect(rotated.keyEpoch.toNumber()).to.equal(2);
+      expect(rotated.keyRotationRequired).to.be.false;
+    });
+
+    it("Reuses a revoked device's slot with a fresh version vector", async () => {
+      const registerDevice = async (deviceId: string) => {
+        await program.methods
+          .initializeSyncState(deviceId, await syncKeyProof(), false)
+          .accountsPartial({
+            owner: userKeypair.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([userKeypair])
+          .rpc();
+        const [syncStatePda] = PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("sync_state"),
+            userKeypair.publicKey.toBuffer(),
+            Buffer.from(deviceId),
+          ],
+          program.programId,
+        );
+        return (await program.account.syncState.fetch(syncStatePda)).deviceSlot;
+      };
+
+      const watchSlot = await registerDevice("watch-001");
+      await program.methods
+        .recordSyncUpdate("watch-001", Array.from(crypto.randomBytes(32)))
+        .accountsPartial({ owner: userKeypair.publicKey })
+        .signers([userKeypair])
+        .rpc();
+      await program.methods
+        .revokeSyncDevice("watch-001")
+        .accountsPartial({ owner: userKeypair.publicKey })
+        .signers([userKeypair])
+        .rpc();
+      syncKeyChain = hashChain(crypto.randomBytes(32), 64);
+      await program.methods
+        .rotateSyncKey(syncKeyCommitment())
+        .accountsPartial({ owner: userKeypair.publicKey })
+        .signers([userKeypair])
+        .rpc();
+
+      // The replacement device gets the same slot, with no trace of the watch's updates
+      expect(await registerDevice("watch-002")).to.equal(watchSlot);
+
+      const registry = await program.account.syncRegistry.fetch(syncRegistryPda);
+      const vectors = registry.versionVectors.map((vector) => vector.map((v) => v.toNumber()));
+      expect(vectors[watchSlot].every((v) => v === 0)).to.be.true;
+      expect(vectors.every((vector) => vector[watchSlot] === 0)).to.be.true;
+
+      await program.methods
+        .revokeSyncDevice("watch-002")
+        .accountsPartial({ owner: userKeypair.publicKey })
+        .signers([userKeypair])
+        .rpc();
+      syncKeyChain = hashChain(crypto.randomBytes(32), 64);
+      await program.methods
+        .rotateSyncKey(syncKeyCommitment())
+        .accountsPartial({ owner: userKeypair.publicKey })
+        .signers([userKeypair])
+        .rpc();
+    });
+
+    it("Lets anyone fail a sync operation after its timeout", async () => {
+      await program.methods
+        .setSyncOperationTimeout(new anchor.BN(1))
+        .accountsPartial({ owner: userKeypair.publicKey })
+        .signers([userKeypair])
+        .rpc();
+
+      const operationId = (await program.account.syncState.fetch(syncStatePda1))
+        .nextOperationId;
+      await program.methods
+        .startSyncOperation(device1Id, device2Id, "incremental", await syncKeyProof())
+        .accountsPartial({
+          owner: userKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([userKeypair])
+        .rpc();
+
+      await new Promise((resolve) => setTimeout(resolve, 2000));
+
+      // The provider wallet is an unrelated third party here
+      const tx = await program.methods
+        .timeoutSyncOperation(userKeypair.publicKey, device1Id, device2Id, operationId)
+        .accountsPartial({ caller: provider.publicKey })
+        .rpc();
+
+      console.log("Timeout sync operation tx:", tx);
+
+      const [operationPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("sync_state"),
+          Buffer.from("operation"),
+          userKeypair.publicKey.toBuffer(),
+          Buffer.from(device1Id),
+          Buffer.from(device2Id),
+          operationId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId,
+      );
+      const syncOperation = await program.account.syncOperation.fetch(operationPda);
+      expect(syncOperation.isSuccessful).to.be.false;
+      expect(syncOperation.completedAt.toNumber()).to.be.greaterThan(0);
+      expect(syncOperation.errorMessage).to.equal("Sync operation timed out");
+    });
+  });
+
+  describe("HMS NFT", () => {
+    const nftName = "HMS Profile NFT";
+    const nftSymbol = "HMSP";
+    const nftUri = "https://example.com/hms-nft.json";
+    const nftPurpose = "health_passport";
+    const metadataProgramId = new PublicKey("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+    let passportMint: Keypair;
+
+    const findMetadataAccounts = (mint: PublicKey) => {
+      const [metadataAccount] = PublicKey.findProgramAddressSync(
+        [Buffer.from("metadata"), metadataProgramId.toBuffer(), mint.toBuffer()],
+        metadataProgramId
+      );
+      const [masterEditionAccount] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("metadata"),
+          metadataProgramId.toBuffer(),
+          mint.toBuffer(),
+          Buffer.from("edition"),
+        ],
+        metadataProgramId
+      );
+      return { metadataAccount, masterEditionAccount };
+    };
+
+    const [nftAuthority] = PublicKey.findProgramAddressSync(
+      [Buffer.from("nft_authority")],
+      program.programId,
+    );
+    const [collectionMint] = PublicKey.findProgramAddressSync(
+      [Buffer.from("hms_collection")],
+      program.programId,
+    );
+
+    before(async () => {
+      const [programData] = PublicKey.findProgramAddressSync(
+        [program.programId.toBuffer()],
+        new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111"),
+      );
+      const collectionPdas = findMetadataAccounts(collectionMint);
+
+      await program.methods
+        .initializeHmsCollection("HMS Health Passports", "HMS", "https://example.com/hms-collection.json")
+        .accounts({
+          nftAuthority,
+          collectionMint,
+          collectionTokenAccount: await anchor.utils.token.associatedAddress({
+            mint: collectionMint,
+            owner: nftAuthority
+          }),
+          collectionMetadata: collectionPdas.metadataAccount,
+          collectionMasterEdition: collectionPdas.masterEditionAccount,
+          programData,
+          authority: provider.publicKey,
+          tokenProgram: anchor.utils.token.TOKEN_PROGRAM_ID,
+          associatedTokenProgram: anchor.utils.token.ASSOCIATED_PROGRAM_ID,
+          tokenMetadataProgram: metadataProgramId,
+          systemProgram: SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+        })
+        .rpc();
+    });
+
+    const collectionAccounts = () => {
+      const { metadataAccount, masterEditionAccount } = findMetadataAccounts(collectionMint);
+      return {
+        nftAuthority,
+        collectionMint,
+        collectionMetadata: metadataAccount,
+        collectionMasterEdition: masterEditionAccount,
+      };
+    };
+
+    const findHmsNftPda = (mint: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("hms_nft"), mint.toBuffer()],
+        program.programId,
+      )[0];
+
+    // SPL token account layout: the state byte follows mint, owner, amount and delegate
+    const isFrozen = async (tokenAccount: PublicKey) => {
+      const info = await provider.connection.getAccountInfo(tokenAccount);
+      return info.data[108] === 2;
+    };
+
+    it("Creates a soulbound HMS NFT", async () => {
+      passportMint = Keypair.generate();
+      const tokenAccount = await anchor.utils.token.associatedAddress({
+        mint: passportMint.publicKey,
+        owner: userKeypair.publicKey
+      });
+      const { metadataAccount, masterEditionAccount } = findMetadataAccounts(passportMint.publicKey);
+
+      const tx = await program.methods
+        .createHmsNft(nftName, nftSymbol, nftUri, nftPurpose, true)
+        .accounts({
+          authority: userKeypair.publicKey,
+          mint: passportMint.publicKey,
+          tokenAccount,
+          metadataAccount,
+          masterEditionAccount,
+          ...collectionAccounts(),
+          tokenProgram: anchor.utils.token.TOKEN_PROGRAM_ID,
+          associatedTokenProgram: anchor.utils.token.ASSOCIATED_PROGRAM_ID,
+          tokenMetadataProgram: metadataProgramId,
+          systemProgram: SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+        })
+        .signers([userKeypair, passportMint])
+        .rpc();
+
+      console.log("Create HMS NFT tx:", tx);
+
+      // Verify the NFT is linked to the holder's profile
+      const hmsNft = await program.account.hmsNft.fetch(findHmsNftPda(passportMint.publicKey));
+      expect(hmsNft.mint.toString()).to.equal(passportMint.publicKey.toString());
+      expect(hmsNft.profile.toString()).to.equal(userProfilePda.toString());
+      expect(hmsNft.owner.toString()).to.equal(userKeypair.publicKey.toString());
+      expect(hmsNft.purpose).to.equal(nftPurpose);
+      expect(hmsNft.isSoulbound).to.be.true;
+      expect(hmsNft.reissuedTo).to.be.null;
+
+      // The token can never leave the holder's wallet
+      expect(await isFrozen(tokenAccount)).to.be.true;
+
+      // Mint and update authority belong to the program, not the caller
+      const mintInfo = await provider.connection.getAccountInfo(passportMint.publicKey);
+      expect(new PublicKey(mintInfo.data.subarray(4, 36)).toString()).to.equal(nftAuthority.toString());
+      const metadataInfo = await provider.connection.getAccountInfo(metadataAccount);
+      expect(new PublicKey(metadataInfo.data.subarray(1, 33)).toString()).to.equal(nftAuthority.toString());
+    });
+
+    it("Re-issues a soulbound HMS NFT to a new wallet", async () => {
+      const newWallet = Keypair.generate();
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(
+          newWallet.publicKey,
+          anchor.web3.LAMPORTS_PER_SOL,
+        ),
+      );
+
+      // The old passport stays frozen in the user's wallet
+      const passportTokenAccount = await anchor.utils.token.associatedAddress({
+        mint: passportMint.publicKey,
+        owner: userKeypair.publicKey
+      });
+
+      // The re-issued NFT is tied to the new wallet's own profile
+      await program.methods
+        .initializeProfile()
+        .accountsPartial({
+          user: newWallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([newWallet])
+        .rpc();
+      const [newWalletProfilePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("user_profile"), newWallet.publicKey.toBuffer()],
+        program.programId,
+      );
+
+      const newMint = Keypair.generate();
+      const tokenAccount = await anchor.utils.token.associatedAddress({
+        mint: newMint.publicKey,
+        owner: newWallet.publicKey
+      });
+      const { metadataAccount, masterEditionAccount } = findMetadataAccounts(newMint.publicKey);
+
+      await program.methods
+        .reissueHmsNft(nftName, nftSymbol, nftUri)
+        .accounts({
+          authority: userKeypair.publicKey,
+          newOwner: newWallet.publicKey,
+          newOwnerProfile: newWalletProfilePda,
+          previousNft: findHmsNftPda(passportMint.publicKey),
+          previousTokenAccount: passportTokenAccount,
+          previousMetadata: findMetadataAccounts(passportMint.publicKey).metadataAccount,
+          mint: newMint.publicKey,
+          tokenAccount,
+          metadataAccount,
+          masterEditionAccount,
+          ...collectionAccounts(),
+          tokenProgram: anchor.utils.token.TOKEN_PROGRAM_ID,
+          associatedTokenProgram: anchor.utils.token.ASSOCIATED_PROGRAM_ID,
+          tokenMetadataProgram: metadataProgramId,
+          systemProgram: SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+        })
+        .signers([userKeypair, newWallet, newMint])
+        .rpc();
+
+      const previousNft = await program.account.hmsNft.fetch(findHmsNftPda(passportMint.publicKey));
+      expect(previousNft.reissuedTo.toString()).to.equal(newMint.publicKey.toString());
+
+      const reissuedNft = await program.account.hmsNft.fetch(findHmsNftPda(newMint.publicKey));
+      expect(reissuedNft.owner.toString()).to.equal(newWallet.publicKey.toString());
+      expect(reissuedNft.profile.toString()).to.equal(newWalletProfilePda.toString());
+      expect(reissuedNft.purpose).to.equal(nftPurpose);
+      expect(reissuedNft.isSoulbound).to.be.true;
+      expect(await isFrozen(tokenAccount)).to.be.true;
+
+      // The old passport cannot be re-issued a second time
+      const otherMint = Keypair.generate();
+      const otherAccounts = findMetadataAccounts(otherMint.publicKey);
+      try {
+        await program.methods
+          .reissueHmsNft(nftName, nftSymbol, nftUri)
+          .accounts({
+            authority: userKeypair.publicKey,
+            newOwner: newWallet.publicKey,
+            newOwnerProfile: newWalletProfilePda,
+            previousNft: findHmsNftPda(passportMint.publicKey),
+            previousTokenAccount: passportTokenAccount,
+            previousMetadata: findMetadataAccounts(passportMint.publicKey).metadataAccount,
+            mint: otherMint.publicKey,
+            tokenAccount: await anchor.utils.token.associatedAddress({
+              mint: otherMint.publicKey,
+              owner: newWallet.publicKey
+            }),
+            metadataAccount: otherAccounts.metadataAccount,
+            masterEditionAccount: otherAccounts.masterEditionAccount,
+            ...collectionAccounts(),
+            tokenProgram: anchor.utils.token.TOKEN_PROGRAM_ID,
+            associatedTokenProgram: anchor.utils.token.ASSOCIATED_PROGRAM_ID,
+            tokenMetadataProgram: metadataProgramId,
+            systemProgram: SystemProgram.programId,
+            rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          })
+          .signers([userKeypair, newWallet, otherMint])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("NftAlreadyReissued");
+      }
+    });
+
+    describe("Health Certificates", () => {
+      const certificateMint = Keypair.generate();
+      const [certificatePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("health_certificate"), certificateMint.publicKey.toBuffer()],
+        program.programId,
+      );
+      const [programData] = PublicKey.findProgramAddressSync(
+        [program.programId.toBuffer()],
+        new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111"),
+      );
+      const [recordPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("health_record"),
+          userKeypair.publicKey.toBuffer(),
+          Buffer.from([1, 0, 0, 0, 0, 0, 0, 0]),
+        ],
+        program.programId,
+      );
+
+      it("Issues a vaccination certificate bound to a health record", async () => {
+        // The doctor's clinic is registered as an issuer
+        await program.methods
+          .registerCertificateIssuer(doctorKeypair.publicKey, "St. Mary's Clinic")
+          .accounts({
+            programData,
+            authority: provider.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+
+        const record = await program.account.healthRecord.fetch(recordPda);
+
+        const { metadataAccount, masterEditionAccount } = findMetadataAccounts(certificateMint.publicKey);
+        const expiresAt = new anchor.BN(Math.floor(Date.now() / 1000) + 365 * 86400);
+
+        await program.methods
+          .issueHealthCertificate(
+            new anchor.BN(1),
+            "vaccination",
+            expiresAt,
+            "HMS Vaccination Certificate",
+            "HMSV",
+            "https://example.com/vaccination.json",
+          )
+          .accounts({
+            issuer: doctorKeypair.publicKey,
+            patient: userKeypair.publicKey,
+            mint: certificateMint.publicKey,
+            tokenAccount: await anchor.utils.token.associatedAddress({
+              mint: certificateMint.publicKey,
+              owner: userKeypair.publicKey
+            }),
+            metadataAccount,
+            masterEditionAccount,
+            ...collectionAccounts(),
+            tokenProgram: anchor.utils.token.TOKEN_PROGRAM_ID,
+            associatedTokenProgram: anchor.utils.token.ASSOCIATED_PROGRAM_ID,
+            tokenMetadataProgram: metadataProgramId,
+            systemProgram: SystemProgram.programId,
+            rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          })
+          .signers([doctorKeypair, userKeypair, certificateMint])
+          .rpc();
+
+        const certificate = await program.account.healthCertificate.fetch(certificatePda);
+        expect(certificate.issuer.toString()).to.equal(doctorKeypair.publicKey.toString());
+        expect(certificate.patient.toString()).to.equal(userKeypair.publicKey.toString());
+        expect(certificate.record.toString()).to.equal(recordPda.toString());
+        expect(certificate.dataHash).to.deep.equal(record.dataHash);
+        expect(certificate.certificateType).to.equal("vaccination");
+      });
+
+      it("Verifies a certificate until it is revoked", async () => {
+        const status = await program.methods
+          .verifyCertificate(certificateMint.publicKey)
+          .accounts({ certificate: certificatePda, record: recordPda })
+          .view();
+        expect(status.isValid).to.be.true;
+        expect(status.issuerRegistered).to.be.true;
+        expect(status.recordMatches).to.be.true;
+        expect(status.patient.toString()).to.equal(userKeypair.publicKey.toString());
+
+        await program.methods
+          .revokeHealthCertificate(certificateMint.publicKey)
+          .accounts({ issuer: doctorKeypair.publicKey })
+          .signers([doctorKeypair])
+          .rpc();
+
+        const revokedStatus = await program.methods
+          .verifyCertificate(certificateMint.publicKey)
+          .accounts({ certificate: certificatePda, record: recordPda })
+          .view();
+        expect(revokedStatus.isValid).to.be.false;
+        expect(revokedStatus.isRevoked).to.be.true;
+        expect(revokedStatus.isExpired).to.be.false;
+      });
+
+      it("Invalidates a certificate once its record is deleted", async () => {
+        const profile = await program.account.userHealthProfile.fetch(userProfilePda);
+        const recordId = profile.recordCount;
+        const [attestedRecordPda] = PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("health_record"),
+            userKeypair.publicKey.toBuffer(),
+            recordId.toArrayLike(Buffer, "le", 8),
+          ],
+          program.programId,
+        );
+
+        await program.methods
+          .addRecord("test_result", "ipfs://QmTestResult", Array.from(Buffer.alloc(32, 7)), "PCR test")
+          .accountsPartial({
+            owner: userKeypair.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([userKeypair])
+          .rpc();
+
+        const testMint = Keypair.generate();
+        const [testCertificatePda] = PublicKey.findProgramAddressSync(
+          [Buffer.from("health_certificate"), testMint.publicKey.toBuffer()],
+          program.programId,
+        );
+        const { metadataAccount, masterEditionAccount } = findMetadataAccounts(testMint.publicKey);
+        await program.methods
+          .issueHealthCertificate(
+            recordId,
+            "test_result",
+            new anchor.BN(Math.floor(Date.now() / 1000) + 30 * 86400),
+            "HMS Test Result Certificate",
+            "HMST",
+            "https://example.com/test-result.json",
+          )
+          .accounts({
+            issuer: doctorKeypair.publicKey,
+            patient: userKeypair.publicKey,
+            mint: testMint.publicKey,
+            tokenAccount: await anchor.utils.token.associatedAddress({
+              mint: testMint.publicKey,
+              owner: userKeypair.publicKey
+            }),
+            metadataAccount,
+            masterEditionAccount,
+            ...collectionAccounts(),
+            tokenProgram: anchor.utils.token.TOKEN_PROGRAM_ID,
+            associatedTokenProgram: anchor.utils.token.ASSOCIATED_PROGRAM_ID,
+            tokenMetadataProgram: metadataProgramId,
+            systemProgram: SystemProgram.programId,
+            rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          })
+          .signers([doctorKeypair, userKeypair, testMint])
+          .rpc();
+
+        await program.methods
+          .deleteRecord(recordId)
+          .accountsPartial({
+            owner: userKeypair.publicKey,
+          })
+          .signers([userKeypair])
+          .rpc();
+
+        const status = await program.methods
+          .verifyCertificate(testMint.publicKey)
+          .accounts({ certificate: testCertificatePda, record: attestedRecordPda })
+          .view();
+        expect(status.isValid).to.be.false;
+        expect(status.recordMatches).to.be.false;
+        expect(status.isRevoked).to.be.false;
+      });
+    });
+  });
+
+  describe("HMS Token", () => {
+    const tokenProgram = anchor.utils.token.TOKEN_PROGRAM_ID;
+    const [configPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("hms_token"), Buffer.from("config")],
+      program.programId,
+    );
+    const [hmsMint] = PublicKey.findProgramAddressSync(
+      [Buffer.from("hms_token"), Buffer.from("mint")],
+      program.programId,
+    );
+    let userTokenAccount: PublicKey;
+    let doctorTokenAccount: PublicKey;
+
+    // Associated token account creation without pulling in @solana/spl-token
+    const createTokenAccountIx = (owner: PublicKey, tokenAccount: PublicKey) =>
+      new TransactionInstruction({
+        programId: anchor.utils.token.ASSOCIATED_PROGRAM_ID,
+        keys: [
+          { pubkey: provider.publicKey, isSigner: true, isWritable: true },
+          { pubkey: tokenAccount, isSigner: false, isWritable: true },
+          { pubkey: owner, isSigner: false, isWritable: false },
+          { pubkey: hmsMint, isSigner: false, isWritable: false },
+          { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+          { pubkey: tokenProgram, isSigner: false, isWritable: false },
+        ],
+        data: Buffer.alloc(0),
+      });
+
+    const balanceOf = async (tokenAccount: PublicKey) =>
+      (await provider.connection.getTokenAccountBalance(tokenAccount)).value.amount;
+
+    before(async () => {
+      const [programData] = PublicKey.findProgramAddressSync(
+        [program.programId.toBuffer()],
+        new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111"),
+      );
+
+      await program.methods
+        .initializeHmsToken(6, new anchor.BN(1_000_000))
+        .accounts({
+          programData,
+          authority: provider.publicKey,
+          tokenProgram,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      userTokenAccount = await anchor.utils.token.associatedAddress({
+        mint: hmsMint,
+        owner: userKeypair.publicKey
+      });
+      doctorTokenAccount = await anchor.utils.token.associatedAddress({
+        mint: hmsMint,
+        owner: doctorKeypair.publicKey
+      });
+      await provider.sendAndConfirm(
+        new Transaction().add(
+          createTokenAccountIx(userKeypair.publicKey, userTokenAccount),
+          createTokenAccountIx(doctorKeypair.publicKey, doctorTokenAccount),
+        ),
+      );
+    });
+
+    it("Rewards a data contribution once per record", async () => {
+      await program.methods
+        .rewardDataContribution(new anchor.BN(1))
+        .accounts({
+          contributor: userKeypair.publicKey,
+          contributorTokenAccount: userTokenAccount,
+          rewarder: provider.publicKey,
+          tokenProgram,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      expect(await balanceOf(userTokenAccount)).to.equal("1000000");
+      const config = await program.account.hmsTokenConfig.fetch(configPda);
+      expect(config.mint.toString()).to.equal(hmsMint.toString());
+      expect(config.totalRewarded.toNumber()).to.equal(1_000_000);
+
+      try {
+        await program.methods
+          .rewardDataContribution(new anchor.BN(1))
+          .accounts({
+            contributor: userKeypair.publicKey,
+            contributorTokenAccount: userTokenAccount,
+            rewarder: provider.publicKey,
+            tokenProgram,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error) {
+        expect(error.message).to.include("already in use");
+      }
+    });
+
+    it("Pays a record owner for granted access", async () => {
+      await program.methods
+        .grantAccess(doctorKeypair.publicKey, new anchor.BN(Date.now() / 1000 + 86400), 1)
+        .accountsPartial({
+          owner: userKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([userKeypair])
+        .rpc();
+
+      // Fund the doctor with an SPL token transfer (instruction 3)
+      const transferData = Buffer.concat([
+        Buffer.from([3]),
+        new anchor.BN(400_000).toArrayLike(Buffer, "le", 8),
+      ]);
+      await provider.sendAndConfirm(
+        new Transaction().add(
+          new TransactionInstruction({
+            programId: tokenProgram,
+            keys: [
+              { pubkey: userTokenAccount, isSigner: false, isWritable: true },
+              { pubkey: doctorTokenAccount, isSigner: false, isWritable: true },
+              { pubkey: userKeypair.publicKey, isSigner: true, isWritable: false },
+            ],
+            data: transferData,
+          }),
+        ),
+        [userKeypair],
+      );
+
+      // Access is paid for through the grant's price, here in HMS per hour
+      const amount = new anchor.BN(250_000);
+      await program.methods
+        .setAccessPrice(doctorKeypair.publicKey, {
+          amount,
+          mint: hmsMint,
+          period: new anchor.BN(3600),
+        })
+        .accountsPartial({
+          owner: userKeypair.publicKey,
+        })
+        .signers([userKeypair])
+        .rpc();
+
+      await program.methods
+        .payAccessPeriod(amount, hmsMint)
+        .accountsPartial({
+          owner: userKeypair.publicKey,
+          grantee: doctorKeypair.publicKey,
+          granteeTokenAccount: doctorTokenAccount,
+          ownerTokenAccount: userTokenAccount,
+          tokenProgram,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([doctorKeypair])
+        .rpc();
+
+      expect(await balanceOf(userTokenAccount)).to.equal("850000");
+      expect(await balanceOf(doctorTokenAccount)).to.equal("150000");
+      const [accessGrantPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("access_grant"),
+          userKeypair.publicKey.toBuffer(),
+          doctorKeypair.publicKey.toBuffer(),
+        ],
+        program.programId,
+      );
+      const accessGrant = await program.account.accessGrant.fetch(accessGrantPda);
+      expect(accessGrant.paidUntil.toNumber()).to.be.greaterThan(Date.now() / 1000);
+    });
+
+    describe("Contribution Rewards", () => {
+      const [schedulePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("hms_token"), Buffer.from("schedule")],
+        program.programId,
+      );
+      const rewardsDeviceId = "rewards-band-001";
+
+      const claimBatchReward = (batchId: number) =>
+        program.methods
+          .claimWearableBatchReward(rewardsDeviceId, new anchor.BN(batchId))
+          .accountsPartial({
+            contributor: userKeypair.publicKey,
+            contributorTokenAccount: userTokenAccount,
+            tokenProgram,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([userKeypair])
+          .rpc();
+
+      before(async () => {
+        const [programData] = PublicKey.findProgramAddressSync(
+          [program.programId.toBuffer()],
+          new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111"),
+        );
+
+        await program.methods
+          .initializeRewardSchedule({
+            wearableBatchReward: new anchor.BN(300_000),
+            attestedRecordReward: new anchor.BN(500_000),
+            epochDuration: new anchor.BN(86400),
+            contributorEpochCap: new anchor.BN(500_000),
+            epochBudget: new anchor.BN(10_000_000),
+          })
+          .accounts({
+            programData,
+            authority: provider.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+
+        await program.methods
+          .initializeContributorRewards()
+          .accountsPartial({
+            contributor: userKeypair.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([userKeypair])
+          .rpc();
+
+        await program.methods
+          .registerWearableDevice(rewardsDeviceId, "fitness_band", Keypair.generate().publicKey)
+          .accountsPartial({
+            owner: userKeypair.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([userKeypair])
+          .rpc();
+
+        const now = Math.floor(Date.now() / 1000);
+        for (const batchId of [1, 2, 3]) {
+          await program.methods
+            .ingestWearableData(
+              rewardsDeviceId,
+              new anchor.BN(batchId),
+              `QmRewardsBatch${batchId}`,
+              Array.from(crypto.randomBytes(32)),
+              60,
+              new anchor.BN(now - 3600),
+              new anchor.BN(now),
+            )
+            .accountsPartial({
+              owner: userKeypair.publicKey,
+              systemProgram: SystemProgram.programId,
+            })
+            .signers([userKeypair])
+            .rpc();
+        }
+      });
+
+      it("Rewards wearable batches up to the per-epoch cap", async () => {
+        await claimBatchReward(1);
+        expect(await balanceOf(userTokenAccount)).to.equal("1150000");
+
+        // Each batch is rewarded once
+        try {
+          await claimBatchReward(1);
+          expect.fail("Should have thrown an error");
+        } catch (error) {
+          expect(error.message).to.include("already in use");
+        }
+
+        // The second batch only gets what is left under the 500k cap
+        await claimBatchReward(2);
+        expect(await balanceOf(userTokenAccount)).to.equal("1350000");
+
+        try {
+          await claimBatchReward(3);
+          expect.fail("Should have thrown an error");
+        } catch (error) {
+          expect(error.message).to.include("RewardEpochCapReached");
+        }
+
+        const [contributorRewardsPda] = PublicKey.findProgramAddressSync(
+          [Buffer.from("hms_token"), Buffer.from("contributor"), userKeypair.publicKey.toBuffer()],
+          program.programId,
+        );
+        const contributorRewards =
+          await program.account.contributorRewards.fetch(contributorRewardsPda);
+        expect(contributorRewards.epochRewarded.toNumber()).to.equal(500_000);
+        const schedule = await program.account.rewardSchedule.fetch(schedulePda);
+        expect(schedule.epochDistributed.toNumber()).to.equal(500_000);
+      });
+
+      it("Changes the reward schedule through governance", async () => {
+        // The DAO tests raised the quorum to two, so the doctor needs a profile to vote
+        await program.methods
+          .initializeProfile()
+          .accountsPartial({
+            user: doctorKeypair.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([doctorKeypair])
+          .rpc();
+
+        const [rewardCounterPda] = PublicKey.findProgramAddressSync(
+          [Buffer.from("dao_governance"), Buffer.from("reward_proposal_counter")],
+          program.programId,
+        );
+        const rewardProposalId = (
+          await program.account.proposalCounter.fetch(rewardCounterPda)
+        ).nextProposalId;
+        await program.methods
+          .createRewardScheduleProposal({
+            wearableBatchReward: new anchor.BN(100_000),
+            attestedRecordReward: new anchor.BN(500_000),
+            epochDuration: new anchor.BN(86400 * 7),
+            contributorEpochCap: new anchor.BN(1_000_000),
+            epochBudget: new anchor.BN(10_000_000),
+          })
+          .accountsPartial({
+            proposer: userKeypair.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([userKeypair])
+          .rpc();
+
+        for (const voter of [userKeypair, doctorKeypair]) {
+          await program.methods
+            .voteOnRewardScheduleProposal(rewardProposalId, true)
+            .accountsPartial({
+              voter: voter.publicKey,
+              systemProgram: SystemProgram.programId,
+            })
+            .signers([voter])
+            .rpc();
+        }
+
+        try {
+          await program.methods
+            .executeRewardScheduleProposal(rewardProposalId)
+            .accountsPartial({ executor: userKeypair.publicKey })
+            .signers([userKeypair])
+            .rpc();
+          expect.fail("Should have thrown an error");
+        } catch (error) {
+          expect(error.message).to.include("VotingPeriodActive");
+        }
+
+        const proposalPda = PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("dao_governance"),
+            Buffer.from("reward_proposal"),
+            rewardProposalId.toArrayLike(Buffer, "le", 8),
+          ],
+          program.programId,
+        )[0];
+        const proposal = await program.account.rewardScheduleProposal.fetch(proposalPda);
+        const waitMs = (proposal.tally.expiresAt.toNumber() + 2) * 1000 - Date.now();
+        await new Promise((resolve) => setTimeout(resolve, Math.max(waitMs, 0)));
+
+        await program.methods
+          .executeRewardScheduleProposal(rewardProposalId)
+          .accountsPartial({ executor: userKeypair.publicKey })
+          .signers([userKeypair])
+          .rpc();
+
+        const schedule = await program.account.rewardSchedule.fetch(schedulePda);
+        expect(schedule.params.wearableBatchReward.toNumber()).to.equal(100_000);
+        expect(schedule.params.contributorEpochCap.toNumber()).to.equal(1_000_000);
+
+        // A longer epoch extends the current one rather than starting a new
+        // epoch, so the contributor keeps what they earned under the raised cap
+        await claimBatchReward(3);
+        expect(await balanceOf(userTokenAccount)).to.equal("1450000");
+        const updatedSchedule = await program.account.rewardSchedule.fetch(schedulePda);
+        expect(updatedSchedule.currentEpoch.toNumber()).to.equal(0);
+        expect(updatedSchedule.epochDistributed.toNumber()).to.equal(600_000);
+      });
+
+      it("Requires a health profile to track contributor rewards", async () => {
+        const outsider = Keypair.generate();
+        await provider.connection.confirmTransaction(
+          await provider.connection.requestAirdrop(outsider.publicKey, anchor.web3.LAMPORTS_PER_SOL),
+        );
+
+        try {
+          await program.methods
+            .initializeContributorRewards()
+            .accountsPartial({
+              contributor: outsider.publicKey,
+              systemProgram: SystemProgram.programId,
+            })
+            .signers([outsider])
+            .rpc();
+          expect.fail("Should have thrown an error");
+        } catch (error) {
+          expect(error.message).to.include("AccountNotInitialized");
+        }
+      });
     });
   });
 });